@@ -0,0 +1,66 @@
+//! Helpers shared by the benchmarks.
+
+// Each bench is its own crate, and not every bench uses every helper.
+#![allow(dead_code)]
+
+use std::time::{Duration, Instant};
+
+use bevy::{
+    input::{
+        keyboard::{Key, KeyboardInput},
+        ButtonState, InputPlugin,
+    },
+    prelude::*,
+    text::TextPlugin,
+};
+use bevy_simple_text_input::TextInputPlugin;
+
+/// An app with everything a text input needs, without UI layout or rendering.
+pub fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        InputPlugin,
+        WindowPlugin {
+            primary_window: None,
+            ..default()
+        },
+    ))
+    .init_asset::<Image>()
+    .init_asset::<TextureAtlasLayout>()
+    .add_plugins((TextPlugin, TextInputPlugin::default()));
+    app
+}
+
+/// Sends a key press to the focused text inputs.
+pub fn send_key(app: &mut App, key_code: KeyCode, logical_key: Key) {
+    app.world_mut().send_event(KeyboardInput {
+        key_code,
+        logical_key,
+        state: ButtonState::Pressed,
+        repeat: false,
+        window: Entity::PLACEHOLDER,
+    });
+}
+
+/// Runs `frame` and then updates the app `frames` times, returning the average time that both took.
+pub fn measure(app: &mut App, frames: u32, mut frame: impl FnMut(&mut App, u32)) -> Duration {
+    let mut total = Duration::ZERO;
+    for i in 0..frames {
+        let start = Instant::now();
+        frame(app, i);
+        app.update();
+        total += start.elapsed();
+    }
+
+    total / frames
+}
+
+/// Prints the time taken by a benchmark next to the time taken by a baseline to compare it with.
+pub fn report(name: &str, time: Duration, baseline_name: &str, baseline: Duration) {
+    println!(
+        "{name}: {time:?} per frame, {baseline_name}: {baseline:?} per frame ({:.2}x)",
+        baseline.as_secs_f64() / time.as_secs_f64()
+    );
+}
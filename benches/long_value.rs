@@ -0,0 +1,86 @@
+//! Measures how long editing a 100 KB value takes.
+//!
+//! Deleting a char is compared with removing it the way the value used to be edited, by collecting every other char
+//! into a new `String`. Word navigation is compared with setting the cursor to the same position directly, which
+//! shows how much finding the word boundary costs. Every frame also updates the displayed text, which takes time
+//! proportional to the length of the value either way.
+//!
+//! Run with `cargo bench --bench long_value`.
+
+mod common;
+
+use std::time::Duration;
+
+use bevy::{
+    input::keyboard::{Key, NativeKey},
+    prelude::*,
+};
+use bevy_simple_text_input::{TextInput, TextInputCursorPos, TextInputValue};
+
+const VALUE_LEN: usize = 100_000;
+const FRAMES: u32 = 200;
+
+fn main() {
+    let (time, _) = bench(&[], |app, _| send(app, KeyCode::Backspace));
+    let (baseline, _) = bench(&[], |app, entity| {
+        let world = app.world_mut();
+        let pos = world.get::<TextInputCursorPos>(entity).unwrap().0 - 1;
+        let value = &mut world.get_mut::<TextInputValue>(entity).unwrap().0;
+        *value = value
+            .chars()
+            .enumerate()
+            .filter_map(|(i, c)| (i != pos).then_some(c))
+            .collect();
+        world.get_mut::<TextInputCursorPos>(entity).unwrap().0 = pos;
+    });
+    common::report("delete a char", time, "full rebuild", baseline);
+
+    for (name, key_code) in [
+        ("move a word left", KeyCode::ArrowLeft),
+        ("move a word right", KeyCode::ArrowRight),
+    ] {
+        let (time, target) = bench(&[KeyCode::ControlLeft], |app, _| send(app, key_code));
+        let (baseline, _) = bench(&[], |app, entity| {
+            app.world_mut()
+                .get_mut::<TextInputCursorPos>(entity)
+                .unwrap()
+                .0 = target;
+        });
+        common::report(name, time, "setting the cursor", baseline);
+    }
+}
+
+// Measures `edit` with the cursor in the middle of the value and `modifiers` held, returning the average time per
+// frame and where the cursor ended up.
+fn bench(modifiers: &[KeyCode], mut edit: impl FnMut(&mut App, Entity)) -> (Duration, usize) {
+    let mut app = common::app();
+
+    // Multi-byte chars make char and byte positions differ.
+    let value = "wörd ".repeat(VALUE_LEN / 6);
+    let middle = value.chars().count() / 2;
+    let entity = app
+        .world_mut()
+        .spawn((TextInput, TextInputValue(value), TextInputCursorPos(middle)))
+        .id();
+    app.update();
+
+    for modifier in modifiers {
+        send(&mut app, *modifier);
+    }
+
+    let time = common::measure(&mut app, FRAMES, |app, _| {
+        // Start from the middle of the value every frame.
+        app.world_mut()
+            .get_mut::<TextInputCursorPos>(entity)
+            .unwrap()
+            .0 = middle;
+        edit(app, entity);
+    });
+
+    let cursor_pos = app.world().get::<TextInputCursorPos>(entity).unwrap().0;
+    (time, cursor_pos)
+}
+
+fn send(app: &mut App, key_code: KeyCode) {
+    common::send_key(app, key_code, Key::Unidentified(NativeKey::Unidentified));
+}
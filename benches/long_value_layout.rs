@@ -0,0 +1,114 @@
+//! Measures how long typing into a text input with a very long value takes, including laying out its text.
+//!
+//! Bevy lays out the whole line again whenever the value changes, so the time taken grows with the length of the
+//! value. Each case is compared with typing into a value of `SHORT_LEN` chars, as if it was limited by
+//! `TextInputSettings::max_length`.
+//!
+//! Run with `cargo bench --bench long_value_layout`.
+
+mod common;
+
+use bevy::{
+    asset::RenderAssetUsages,
+    audio::AudioPlugin,
+    input::keyboard::Key,
+    log::LogPlugin,
+    prelude::*,
+    render::{
+        camera::RenderTarget,
+        render_resource::{Extent3d, TextureDimension, TextureFormat},
+        settings::{RenderCreation, WgpuSettings},
+        RenderPlugin,
+    },
+    window::ExitCondition,
+    winit::WinitPlugin,
+};
+use bevy_simple_text_input::{TextInput, TextInputPlugin, TextInputValue};
+
+const SHORT_LEN: usize = 1_000;
+const FRAMES: u32 = 20;
+
+fn main() {
+    let baseline = bench(SHORT_LEN);
+
+    for len in [10_000, 50_000] {
+        let time = bench(len);
+        common::report(
+            &format!("type into a {len} char value"),
+            time,
+            &format!("{SHORT_LEN} chars"),
+            baseline,
+        );
+    }
+}
+
+fn bench(len: usize) -> std::time::Duration {
+    let mut app = app();
+
+    app.world_mut().spawn((
+        Node {
+            width: Val::Px(300.0),
+            ..default()
+        },
+        TextInput,
+        TextInputValue("lorem ".repeat(len / 6)),
+    ));
+
+    // Lay out the initial value, which may take a few frames.
+    for _ in 0..3 {
+        app.update();
+    }
+
+    common::measure(&mut app, FRAMES, |app, _| {
+        common::send_key(app, KeyCode::KeyA, Key::Character("a".into()));
+    })
+}
+
+// An app that lays out UI without a window or a GPU, rendering to an image.
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins(
+        DefaultPlugins
+            .set(WindowPlugin {
+                primary_window: None,
+                exit_condition: ExitCondition::DontExit,
+                ..default()
+            })
+            .set(RenderPlugin {
+                render_creation: RenderCreation::Automatic(WgpuSettings {
+                    backends: None,
+                    ..default()
+                }),
+                ..default()
+            })
+            .disable::<WinitPlugin>()
+            .disable::<AudioPlugin>()
+            .disable::<LogPlugin>(),
+    )
+    .add_plugins(TextInputPlugin::default());
+    app.finish();
+    app.cleanup();
+
+    let image = Image::new_fill(
+        Extent3d {
+            width: 800,
+            height: 600,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[0; 4],
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    let image = app.world_mut().resource_mut::<Assets<Image>>().add(image);
+    app.world_mut().spawn((
+        Camera2d,
+        Camera {
+            target: RenderTarget::Image(image),
+            ..default()
+        },
+        IsDefaultUiCamera,
+    ));
+
+    app
+}
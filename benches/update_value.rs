@@ -0,0 +1,59 @@
+//! Measures how long it takes to update the displayed text of many text inputs.
+//!
+//! Each case is compared with a baseline that replaces every section of the inner text with a newly allocated
+//! `String`, like `update_value` did before it started reusing the sections' buffers.
+//!
+//! Run with `cargo bench --bench update_value`.
+
+mod common;
+
+use bevy::{input::keyboard::Key, prelude::*};
+use bevy_simple_text_input::{TextInput, TextInputCursorPos, TextInputValue};
+
+const INPUTS: usize = 500;
+const VALUE_LEN: usize = 200;
+const FRAMES: u32 = 200;
+
+fn main() {
+    bench("move the cursor of every input", |app, frame| {
+        let mut query = app.world_mut().query::<&mut TextInputCursorPos>();
+        for mut cursor_pos in query.iter_mut(app.world_mut()) {
+            cursor_pos.0 = frame as usize % VALUE_LEN;
+        }
+    });
+
+    bench("type into every input", |app, _| {
+        common::send_key(app, KeyCode::KeyA, Key::Character("a".into()));
+    });
+}
+
+fn bench(name: &str, mut frame: impl FnMut(&mut App, u32)) {
+    let time = common::measure(&mut setup(), FRAMES, &mut frame);
+    let baseline = common::measure(&mut setup(), FRAMES, |app, i| {
+        frame(app, i);
+        rebuild_sections(app);
+    });
+
+    common::report(name, time, "full rebuild", baseline);
+}
+
+fn setup() -> App {
+    let mut app = common::app();
+
+    let value = "abcdefghij".repeat(VALUE_LEN / 10);
+    for _ in 0..INPUTS {
+        app.world_mut()
+            .spawn((TextInput, TextInputValue(value.clone())));
+    }
+    app.update();
+
+    app
+}
+
+// Drops the buffers of every section, so that `update_value` has to allocate all of them again.
+fn rebuild_sections(app: &mut App) {
+    let mut query = app.world_mut().query::<&mut TextSpan>();
+    for mut span in query.iter_mut(app.world_mut()) {
+        span.0 = String::new();
+    }
+}
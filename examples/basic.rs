@@ -13,7 +13,7 @@ const BACKGROUND_COLOR: Color = Color::srgb(0.15, 0.15, 0.15);
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(TextInputPlugin)
+        .add_plugins(TextInputPlugin::default())
         .add_systems(Startup, setup)
         .add_systems(Update, listener.after(TextInputSystem))
         .run();
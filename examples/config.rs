@@ -0,0 +1,80 @@
+//! An example showing how to configure a text input with a single component.
+
+use bevy::prelude::*;
+use bevy_simple_text_input::{
+    TextInputConfig, TextInputPlaceholder, TextInputPlugin, TextInputSettings, TextInputTextColor,
+};
+
+const BORDER_COLOR_ACTIVE: Color = Color::srgb(0.75, 0.52, 0.99);
+const TEXT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+const BACKGROUND_COLOR: Color = Color::srgb(0.15, 0.15, 0.15);
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(TextInputPlugin::default())
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            row_gap: Val::Px(10.0),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                text_input_node(),
+                BorderColor(BORDER_COLOR_ACTIVE),
+                BackgroundColor(BACKGROUND_COLOR),
+                TextInputConfig {
+                    text_font: TextFont {
+                        font_size: 34.,
+                        ..default()
+                    },
+                    text_color: TextColor(TEXT_COLOR),
+                    placeholder: TextInputPlaceholder {
+                        value: "Configured in one place".to_string(),
+                        ..default()
+                    },
+                    settings: TextInputSettings {
+                        retain_on_submit: true,
+                        ..default()
+                    },
+                },
+            ));
+
+            // Individual components take precedence over the config.
+            parent.spawn((
+                text_input_node(),
+                BorderColor(BORDER_COLOR_ACTIVE),
+                BackgroundColor(BACKGROUND_COLOR),
+                TextInputConfig {
+                    text_font: TextFont {
+                        font_size: 34.,
+                        ..default()
+                    },
+                    text_color: TextColor(TEXT_COLOR),
+                    ..default()
+                },
+                TextInputTextColor(TextColor(BORDER_COLOR_ACTIVE)),
+            ));
+        });
+}
+
+fn text_input_node() -> Node {
+    Node {
+        width: Val::Px(300.0),
+        border: UiRect::all(Val::Px(5.0)),
+        padding: UiRect::all(Val::Px(5.0)),
+        ..default()
+    }
+}
@@ -0,0 +1,80 @@
+//! An example showing a command console where previously submitted commands can be recalled with the up and down
+//! arrow keys.
+//!
+//! The console is a plugin of its own, the way it would be added to a game. Keys typed into it are hidden from the
+//! game's systems.
+
+use bevy::prelude::*;
+use bevy_simple_text_input::{
+    TextInput, TextInputGameInputPlugin, TextInputPlaceholder, TextInputPlugin,
+    TextInputSubmitEvent, TextInputSubmitHistory, TextInputSystem, TextInputTextColor,
+    TextInputTextFont,
+};
+
+const BORDER_COLOR_ACTIVE: Color = Color::srgb(0.75, 0.52, 0.99);
+const TEXT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+const BACKGROUND_COLOR: Color = Color::srgb(0.15, 0.15, 0.15);
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(TextInputPlugin::default())
+        .add_plugins(ConsolePlugin)
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+}
+
+struct ConsolePlugin;
+
+impl Plugin for ConsolePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(TextInputGameInputPlugin::default())
+            .add_systems(Startup, spawn_console)
+            .add_systems(Update, listener.after(TextInputSystem));
+    }
+}
+
+fn spawn_console(mut commands: Commands) {
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            align_items: AlignItems::End,
+            justify_content: JustifyContent::Center,
+            padding: UiRect::all(Val::Px(10.0)),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                Node {
+                    width: Val::Percent(100.0),
+                    border: UiRect::all(Val::Px(5.0)),
+                    padding: UiRect::all(Val::Px(5.0)),
+                    ..default()
+                },
+                BorderColor(BORDER_COLOR_ACTIVE),
+                BackgroundColor(BACKGROUND_COLOR),
+                TextInput,
+                TextInputTextFont(TextFont {
+                    font_size: 24.,
+                    ..default()
+                }),
+                TextInputTextColor(TextColor(TEXT_COLOR)),
+                TextInputPlaceholder {
+                    value: "Type a command, or press Up to recall one".to_string(),
+                    ..default()
+                },
+                TextInputSubmitHistory::new(50),
+            ));
+        });
+}
+
+fn listener(mut events: EventReader<TextInputSubmitEvent>) {
+    for event in events.read() {
+        info!("> {}", event.value);
+    }
+}
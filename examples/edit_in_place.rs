@@ -0,0 +1,122 @@
+//! An example showing labels that turn into text inputs when clicked, and back into labels when submitted.
+
+use bevy::prelude::*;
+use bevy_simple_text_input::{
+    TextInput, TextInputEditInPlace, TextInputPlugin, TextInputSettings, TextInputSubmitEvent,
+    TextInputSystem, TextInputTextColor, TextInputTextFont, TextInputValue,
+};
+
+const BORDER_COLOR: Color = Color::srgb(0.25, 0.25, 0.25);
+const TEXT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+const BACKGROUND_COLOR: Color = Color::srgb(0.15, 0.15, 0.15);
+
+#[derive(Component)]
+struct Cell(String);
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(TextInputPlugin::default())
+        .add_systems(Startup, setup)
+        .add_systems(
+            Update,
+            (
+                start_editing.before(TextInputSystem),
+                stop_editing.after(TextInputSystem),
+            ),
+        )
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            row_gap: Val::Px(10.0),
+            ..default()
+        })
+        .with_children(|parent| {
+            for value in ["Apples", "Oranges", "Pears"] {
+                parent
+                    .spawn((
+                        Node {
+                            width: Val::Px(200.0),
+                            border: UiRect::all(Val::Px(5.0)),
+                            padding: UiRect::all(Val::Px(5.0)),
+                            ..default()
+                        },
+                        BorderColor(BORDER_COLOR),
+                        BackgroundColor(BACKGROUND_COLOR),
+                        Button,
+                        Cell(value.to_string()),
+                    ))
+                    .with_child(label(value));
+            }
+        });
+}
+
+fn label(value: &str) -> impl Bundle {
+    (
+        Text::new(value),
+        TextFont {
+            font_size: 34.,
+            ..default()
+        },
+        TextColor(TEXT_COLOR),
+    )
+}
+
+fn start_editing(
+    mut commands: Commands,
+    query: Query<(Entity, &Interaction, &Cell), (Changed<Interaction>, Without<TextInput>)>,
+) {
+    for (entity, interaction, cell) in &query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        // Replace the label with a text input that is already focused.
+        commands.entity(entity).despawn_descendants().insert((
+            TextInput,
+            TextInputValue(cell.0.clone()),
+            TextInputTextFont(TextFont {
+                font_size: 34.,
+                ..default()
+            }),
+            TextInputTextColor(TextColor(TEXT_COLOR)),
+            TextInputSettings {
+                retain_on_submit: true,
+                blur_on_submit: true,
+                focus_on_click: true,
+                ..default()
+            },
+            TextInputEditInPlace,
+        ));
+    }
+}
+
+fn stop_editing(
+    mut commands: Commands,
+    mut events: EventReader<TextInputSubmitEvent>,
+    mut query: Query<&mut Cell>,
+) {
+    for event in events.read() {
+        let Ok(mut cell) = query.get_mut(event.entity) else {
+            continue;
+        };
+
+        cell.0.clone_from(&event.value);
+
+        // Turn the text input back into a label.
+        commands
+            .entity(event.entity)
+            .remove::<(TextInput, TextInputValue, TextInputEditInPlace)>()
+            .with_child(label(&event.value));
+    }
+}
@@ -2,8 +2,8 @@
 
 use bevy::{prelude::*, ui::FocusPolicy};
 use bevy_simple_text_input::{
-    TextInput, TextInputInactive, TextInputPlaceholder, TextInputPlugin, TextInputSystem,
-    TextInputTextColor, TextInputTextFont,
+    TextInput, TextInputFocusStyle, TextInputInactive, TextInputPlaceholder, TextInputPlugin,
+    TextInputSystem, TextInputTextColor, TextInputTextFont,
 };
 
 const BORDER_COLOR_ACTIVE: Color = Color::srgb(0.75, 0.52, 0.99);
@@ -14,7 +14,7 @@ const BACKGROUND_COLOR: Color = Color::srgb(0.15, 0.15, 0.15);
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(TextInputPlugin)
+        .add_plugins(TextInputPlugin::default())
         .add_systems(Startup, setup)
         .add_systems(Update, focus.before(TextInputSystem))
         .run();
@@ -44,7 +44,10 @@ fn setup(mut commands: Commands) {
                     padding: UiRect::all(Val::Px(5.0)),
                     ..default()
                 },
-                BorderColor(BORDER_COLOR_INACTIVE),
+                TextInputFocusStyle {
+                    active: BORDER_COLOR_ACTIVE,
+                    inactive: BORDER_COLOR_INACTIVE,
+                },
                 BackgroundColor(BACKGROUND_COLOR),
                 // Prevent clicks on the input from also bubbling down to the container
                 // behind it
@@ -66,18 +69,12 @@ fn setup(mut commands: Commands) {
 
 fn focus(
     query: Query<(Entity, &Interaction), Changed<Interaction>>,
-    mut text_input_query: Query<(Entity, &mut TextInputInactive, &mut BorderColor)>,
+    mut text_input_query: Query<(Entity, &mut TextInputInactive)>,
 ) {
     for (interaction_entity, interaction) in &query {
         if *interaction == Interaction::Pressed {
-            for (entity, mut inactive, mut border_color) in &mut text_input_query {
-                if entity == interaction_entity {
-                    inactive.0 = false;
-                    *border_color = BORDER_COLOR_ACTIVE.into();
-                } else {
-                    inactive.0 = true;
-                    *border_color = BORDER_COLOR_INACTIVE.into();
-                }
+            for (entity, mut inactive) in &mut text_input_query {
+                inactive.0 = entity != interaction_entity;
             }
         }
     }
@@ -0,0 +1,117 @@
+//! An example showing how to stop gameplay systems from reacting to keys typed into a chat box.
+//!
+//! Move the square with WASD. Press Enter to focus the chat box, and Enter again to send a message.
+
+use bevy::prelude::*;
+use bevy_simple_text_input::{
+    TextInput, TextInputGameInputPlugin, TextInputInactive, TextInputPlaceholder, TextInputPlugin,
+    TextInputSettings, TextInputSubmitEvent, TextInputSystem, TextInputTextColor,
+    TextInputTextFont,
+};
+
+const BORDER_COLOR_ACTIVE: Color = Color::srgb(0.75, 0.52, 0.99);
+const TEXT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+const BACKGROUND_COLOR: Color = Color::srgb(0.15, 0.15, 0.15);
+
+#[derive(Component)]
+struct Player;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(TextInputPlugin::default())
+        // Hides the keys typed into the chat box from `movement` and `focus_chat`.
+        .add_plugins(TextInputGameInputPlugin::default())
+        .add_systems(Startup, setup)
+        .add_systems(
+            Update,
+            (movement, focus_chat, listener).after(TextInputSystem),
+        )
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands.spawn((
+        Sprite::from_color(BORDER_COLOR_ACTIVE, Vec2::splat(50.0)),
+        Player,
+    ));
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            align_items: AlignItems::End,
+            padding: UiRect::all(Val::Px(10.0)),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                Node {
+                    width: Val::Px(300.0),
+                    border: UiRect::all(Val::Px(5.0)),
+                    padding: UiRect::all(Val::Px(5.0)),
+                    ..default()
+                },
+                BorderColor(BORDER_COLOR_ACTIVE),
+                BackgroundColor(BACKGROUND_COLOR),
+                TextInput,
+                TextInputTextFont(TextFont {
+                    font_size: 24.,
+                    ..default()
+                }),
+                TextInputTextColor(TextColor(TEXT_COLOR)),
+                TextInputPlaceholder {
+                    value: "Press Enter to chat".to_string(),
+                    ..default()
+                },
+                TextInputSettings {
+                    blur_on_submit: true,
+                    ..default()
+                },
+                TextInputInactive(true),
+            ));
+        });
+}
+
+fn movement(
+    keys: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    mut query: Query<&mut Transform, With<Player>>,
+) {
+    let mut direction = Vec2::ZERO;
+    if keys.pressed(KeyCode::KeyW) {
+        direction.y += 1.0;
+    }
+    if keys.pressed(KeyCode::KeyS) {
+        direction.y -= 1.0;
+    }
+    if keys.pressed(KeyCode::KeyA) {
+        direction.x -= 1.0;
+    }
+    if keys.pressed(KeyCode::KeyD) {
+        direction.x += 1.0;
+    }
+
+    for mut transform in &mut query {
+        transform.translation +=
+            (direction.normalize_or_zero() * 300.0 * time.delta_secs()).extend(0.0);
+    }
+}
+
+fn focus_chat(keys: Res<ButtonInput<KeyCode>>, mut query: Query<&mut TextInputInactive>) {
+    if !keys.just_pressed(KeyCode::Enter) {
+        return;
+    }
+
+    for mut inactive in &mut query {
+        inactive.0 = false;
+    }
+}
+
+fn listener(mut events: EventReader<TextInputSubmitEvent>) {
+    for event in events.read() {
+        info!("chat: {}", event.value);
+    }
+}
@@ -0,0 +1,85 @@
+//! An example showing a text input that only accepts numbers within a range.
+//!
+//! The border flashes red when a key that isn't part of a number is typed.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_simple_text_input::{
+    TextInput, TextInputNumeric, TextInputNumericValue, TextInputPlaceholder, TextInputPlugin,
+    TextInputRejectFlash, TextInputSettings, TextInputSystem, TextInputTextColor,
+    TextInputTextFont,
+};
+
+const BORDER_COLOR_ACTIVE: Color = Color::srgb(0.75, 0.52, 0.99);
+const TEXT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+const BACKGROUND_COLOR: Color = Color::srgb(0.15, 0.15, 0.15);
+const REJECT_COLOR: Color = Color::srgb(0.9, 0.2, 0.2);
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(TextInputPlugin::default())
+        .add_systems(Startup, setup)
+        .add_systems(Update, listener.after(TextInputSystem))
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                Node {
+                    width: Val::Px(200.0),
+                    border: UiRect::all(Val::Px(5.0)),
+                    padding: UiRect::all(Val::Px(5.0)),
+                    ..default()
+                },
+                BorderColor(BORDER_COLOR_ACTIVE),
+                BackgroundColor(BACKGROUND_COLOR),
+                TextInput,
+                TextInputTextFont(TextFont {
+                    font_size: 34.,
+                    ..default()
+                }),
+                TextInputTextColor(TextColor(TEXT_COLOR)),
+                TextInputPlaceholder {
+                    value: "0 - 100".to_string(),
+                    ..default()
+                },
+                TextInputSettings {
+                    retain_on_submit: true,
+                    ..default()
+                },
+                TextInputNumeric {
+                    min: Some(0.0),
+                    max: Some(100.0),
+                    ..default()
+                },
+                TextInputRejectFlash::new(REJECT_COLOR, Duration::from_millis(300)),
+            ));
+        });
+}
+
+fn listener(query: Query<Ref<TextInputNumericValue>>) {
+    for value in &query {
+        // Skip the change caused by spawning the text input.
+        if !value.is_changed() || value.is_added() {
+            continue;
+        }
+
+        match value.0 {
+            Some(number) => info!("submitted: {number}"),
+            None => info!("not a number"),
+        }
+    }
+}
@@ -13,7 +13,7 @@ const BACKGROUND_COLOR: Color = Color::srgb(0.15, 0.15, 0.15);
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_plugins(TextInputPlugin)
+        .add_plugins(TextInputPlugin::default())
         .add_systems(Startup, setup)
         .run();
 }
@@ -49,6 +49,7 @@ fn setup(mut commands: Commands) {
                 TextInputSettings {
                     mask_character: Some('*'),
                     retain_on_submit: true,
+                    ..default()
                 },
             ));
         });
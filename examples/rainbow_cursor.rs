@@ -0,0 +1,63 @@
+//! An example showing a cursor that cycles through the colors of the rainbow.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_simple_text_input::{
+    TextInput, TextInputCursorBlink, TextInputCursorTimer, TextInputPlugin, TextInputTextColor,
+    TextInputTextFont,
+};
+
+const BORDER_COLOR_ACTIVE: Color = Color::srgb(0.75, 0.52, 0.99);
+const TEXT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+const BACKGROUND_COLOR: Color = Color::srgb(0.15, 0.15, 0.15);
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(TextInputPlugin::default())
+        .add_systems(Startup, setup)
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let rainbow: Vec<Color> = (0..6)
+        .map(|i| Color::hsl(i as f32 * 60.0, 1.0, 0.6))
+        .collect();
+
+    // Fade between colors for the whole duration of each step.
+    let mut cursor_timer = TextInputCursorTimer::default();
+    cursor_timer.timer = Timer::from_seconds(0.3, TimerMode::Repeating);
+    cursor_timer.fade = Some(Duration::from_millis(300));
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                Node {
+                    width: Val::Px(200.0),
+                    border: UiRect::all(Val::Px(5.0)),
+                    padding: UiRect::all(Val::Px(5.0)),
+                    ..default()
+                },
+                BorderColor(BORDER_COLOR_ACTIVE),
+                BackgroundColor(BACKGROUND_COLOR),
+                TextInput,
+                TextInputTextFont(TextFont {
+                    font_size: 34.,
+                    ..default()
+                }),
+                TextInputTextColor(TextColor(TEXT_COLOR)),
+                TextInputCursorBlink::new(rainbow),
+                cursor_timer,
+            ));
+        });
+}
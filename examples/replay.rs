@@ -0,0 +1,121 @@
+//! An example showing how to record the edits made to a text input and replay them into another one.
+//!
+//! Type into the top text input and press Enter. Everything that was typed is then replayed into the bottom one
+//! with the same timing.
+//!
+//! Run with `cargo run --example replay --features recording`.
+
+use bevy::prelude::*;
+use bevy_simple_text_input::{
+    TextInput, TextInputInactive, TextInputPlugin, TextInputRecordedAction, TextInputRecording,
+    TextInputReplay, TextInputSettings, TextInputSubmitEvent, TextInputSystem, TextInputTextColor,
+    TextInputTextFont, TextInputValue,
+};
+
+const BORDER_COLOR_ACTIVE: Color = Color::srgb(0.75, 0.52, 0.99);
+const BORDER_COLOR_INACTIVE: Color = Color::srgb(0.25, 0.25, 0.25);
+const TEXT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+const BACKGROUND_COLOR: Color = Color::srgb(0.15, 0.15, 0.15);
+
+#[derive(Component)]
+struct Playback;
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(TextInputPlugin::default())
+        .add_systems(Startup, setup)
+        .add_systems(Update, replay.after(TextInputSystem))
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            row_gap: Val::Px(10.0),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                text_input_node(),
+                BorderColor(BORDER_COLOR_ACTIVE),
+                BackgroundColor(BACKGROUND_COLOR),
+                TextInput,
+                TextInputTextFont(TextFont {
+                    font_size: 34.,
+                    ..default()
+                }),
+                TextInputTextColor(TextColor(TEXT_COLOR)),
+                TextInputRecording::default(),
+            ));
+
+            parent.spawn((
+                text_input_node(),
+                BorderColor(BORDER_COLOR_INACTIVE),
+                BackgroundColor(BACKGROUND_COLOR),
+                TextInput,
+                TextInputTextFont(TextFont {
+                    font_size: 34.,
+                    ..default()
+                }),
+                TextInputTextColor(TextColor(TEXT_COLOR)),
+                TextInputSettings {
+                    retain_on_submit: true,
+                    ..default()
+                },
+                TextInputInactive(true),
+                Playback,
+            ));
+        });
+}
+
+fn text_input_node() -> Node {
+    Node {
+        width: Val::Px(300.0),
+        border: UiRect::all(Val::Px(5.0)),
+        padding: UiRect::all(Val::Px(5.0)),
+        ..default()
+    }
+}
+
+fn replay(
+    mut commands: Commands,
+    mut events: EventReader<TextInputSubmitEvent>,
+    mut recording_query: Query<&mut TextInputRecording>,
+    playback_query: Query<Entity, With<Playback>>,
+) {
+    for event in events.read() {
+        let Ok(mut recording) = recording_query.get_mut(event.entity) else {
+            continue;
+        };
+
+        // Skip the pause before the first action.
+        let start = recording.entries.first().map(|entry| entry.time);
+        let replay =
+            TextInputReplay::new(
+                recording
+                    .entries
+                    .iter()
+                    .map(|entry| TextInputRecordedAction {
+                        time: entry.time - start.unwrap_or_default(),
+                        action: entry.action.clone(),
+                    }),
+            );
+
+        // Start a new recording for the next submission.
+        *recording = TextInputRecording::default();
+
+        for entity in &playback_query {
+            commands
+                .entity(entity)
+                .insert((replay.clone(), TextInputValue::default()));
+        }
+    }
+}
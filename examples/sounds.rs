@@ -0,0 +1,101 @@
+//! An example showing how to play sounds when typing into and submitting a text input, and when typed text is
+//! rejected.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_simple_text_input::{
+    TextInput, TextInputAction, TextInputKeyEvent, TextInputKeyEvents, TextInputPlugin,
+    TextInputRejectEvent, TextInputSettings, TextInputSubmitEvent, TextInputSystem,
+    TextInputTextColor, TextInputTextFont,
+};
+
+const BORDER_COLOR_ACTIVE: Color = Color::srgb(0.75, 0.52, 0.99);
+const TEXT_COLOR: Color = Color::srgb(0.9, 0.9, 0.9);
+const BACKGROUND_COLOR: Color = Color::srgb(0.15, 0.15, 0.15);
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(TextInputPlugin::default())
+        .add_systems(Startup, setup)
+        .add_systems(
+            Update,
+            (keystroke_sound, submit_sound).after(TextInputSystem),
+        )
+        .run();
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                Node {
+                    width: Val::Px(200.0),
+                    border: UiRect::all(Val::Px(5.0)),
+                    padding: UiRect::all(Val::Px(5.0)),
+                    ..default()
+                },
+                BorderColor(BORDER_COLOR_ACTIVE),
+                BackgroundColor(BACKGROUND_COLOR),
+                TextInput,
+                TextInputSettings {
+                    max_length: Some(10),
+                    ..default()
+                },
+                TextInputKeyEvents,
+                TextInputTextFont(TextFont {
+                    font_size: 34.,
+                    ..default()
+                }),
+                TextInputTextColor(TextColor(TEXT_COLOR)),
+            ));
+        });
+}
+
+fn play(commands: &mut Commands, pitches: &mut Assets<Pitch>, frequency: f32, millis: u64) {
+    commands.spawn((
+        AudioPlayer(pitches.add(Pitch::new(frequency, Duration::from_millis(millis)))),
+        PlaybackSettings::DESPAWN,
+    ));
+}
+
+fn keystroke_sound(
+    mut commands: Commands,
+    mut key_events: EventReader<TextInputKeyEvent>,
+    mut reject_events: EventReader<TextInputRejectEvent>,
+    mut pitches: ResMut<Assets<Pitch>>,
+) {
+    let rejected: Vec<Entity> = reject_events.read().map(|event| event.entity).collect();
+    for _ in &rejected {
+        play(&mut commands, &mut pitches, 220.0, 60);
+    }
+
+    for event in key_events.read() {
+        // Submitting has its own sound, and rejected input already played one.
+        if matches!(event.action, TextInputAction::Submit) || rejected.contains(&event.entity) {
+            continue;
+        }
+
+        play(&mut commands, &mut pitches, 880.0, 20);
+    }
+}
+
+fn submit_sound(
+    mut commands: Commands,
+    mut events: EventReader<TextInputSubmitEvent>,
+    mut pitches: ResMut<Assets<Pitch>>,
+) {
+    for _ in events.read() {
+        play(&mut commands, &mut pitches, 440.0, 100);
+    }
+}
@@ -0,0 +1,187 @@
+//! An example showing a numeric stepper with buttons and arrow keys to change the value within a range.
+
+use bevy::prelude::*;
+use bevy_simple_text_input::{
+    TextInput, TextInputInactive, TextInputNumeric, TextInputNumericValue, TextInputPlugin,
+    TextInputSettings, TextInputSystem, TextInputTextColor, TextInputTextFont, TextInputValue,
+};
+
+const BORDER_COLOR_ACTIVE: Color = Color::srgb(0.75, 0.52, 0.99);
+const BORDER_COLOR_INACTIVE: Color = Color::srgb(0.25, 0.25, 0.25);
+const BORDER_COLOR_HOVER: Color = Color::srgb(0.9, 0.9, 0.9);
+const TEXT_COLOR: Color = BORDER_COLOR_HOVER;
+const BACKGROUND_COLOR: Color = Color::srgb(0.15, 0.15, 0.15);
+
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins)
+        .add_plugins(TextInputPlugin::default())
+        .add_systems(Startup, setup)
+        .add_systems(
+            Update,
+            (
+                button_system,
+                keyboard_system.before(TextInputSystem),
+                button_style_system,
+                listener.after(TextInputSystem),
+            ),
+        )
+        .run();
+}
+
+#[derive(Component)]
+struct StepButton(f64);
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d);
+
+    let text_font = TextFont {
+        font_size: 40.,
+        ..default()
+    };
+    let text_color = TextColor(TEXT_COLOR);
+
+    commands
+        .spawn(Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            column_gap: Val::Px(10.),
+            ..default()
+        })
+        .with_children(|parent| {
+            spawn_button(parent, "-", -1.0, &text_font, text_color);
+
+            parent.spawn((
+                Node {
+                    width: Val::Px(120.0),
+                    border: UiRect::all(Val::Px(5.0)),
+                    padding: UiRect::all(Val::Px(5.0)),
+                    ..default()
+                },
+                BorderColor(BORDER_COLOR_ACTIVE),
+                BackgroundColor(BACKGROUND_COLOR),
+                TextInput,
+                TextInputTextFont(text_font.clone()),
+                TextInputTextColor(text_color),
+                TextInputValue("5".to_string()),
+                TextInputSettings {
+                    retain_on_submit: true,
+                    ..default()
+                },
+                TextInputNumeric {
+                    min: Some(0.0),
+                    max: Some(10.0),
+                    ..default()
+                },
+            ));
+
+            spawn_button(parent, "+", 1.0, &text_font, text_color);
+        });
+}
+
+fn spawn_button(
+    parent: &mut ChildBuilder,
+    label: &str,
+    delta: f64,
+    text_font: &TextFont,
+    text_color: TextColor,
+) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                width: Val::Px(50.),
+                border: UiRect::all(Val::Px(5.0)),
+                padding: UiRect::all(Val::Px(5.0)),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            BorderColor(BORDER_COLOR_INACTIVE),
+            BackgroundColor(BACKGROUND_COLOR),
+            StepButton(delta),
+        ))
+        .with_children(|parent| {
+            parent.spawn((Text::new(label), text_font.clone(), text_color));
+        });
+}
+
+fn button_system(
+    interaction_query: Query<(&Interaction, &StepButton), Changed<Interaction>>,
+    mut text_input_query: Query<(
+        &TextInputNumeric,
+        &mut TextInputValue,
+        &mut TextInputNumericValue,
+    )>,
+) {
+    for (interaction, step) in &interaction_query {
+        if !matches!(interaction, Interaction::Pressed) {
+            continue;
+        }
+
+        for (numeric, mut value, mut number) in &mut text_input_query {
+            number.0 = Some(numeric.step(&mut value, step.0));
+        }
+    }
+}
+
+// Up and down step the value of the focused text input.
+fn keyboard_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut text_input_query: Query<(
+        &TextInputNumeric,
+        &TextInputInactive,
+        &mut TextInputValue,
+        &mut TextInputNumericValue,
+    )>,
+) {
+    let delta = if keys.just_pressed(KeyCode::ArrowUp) {
+        1.0
+    } else if keys.just_pressed(KeyCode::ArrowDown) {
+        -1.0
+    } else {
+        return;
+    };
+
+    for (numeric, inactive, mut value, mut number) in &mut text_input_query {
+        if !inactive.0 {
+            number.0 = Some(numeric.step(&mut value, delta));
+        }
+    }
+}
+
+fn listener(query: Query<Ref<TextInputNumericValue>>) {
+    for value in &query {
+        // Skip the change caused by spawning the text input.
+        if !value.is_changed() || value.is_added() {
+            continue;
+        }
+
+        match value.0 {
+            Some(number) => info!("value: {number}"),
+            None => info!("not a number"),
+        }
+    }
+}
+
+fn button_style_system(
+    mut interaction_query: Query<
+        (&Interaction, &mut BorderColor),
+        (Changed<Interaction>, With<Button>),
+    >,
+) {
+    for (interaction, mut border_color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                border_color.0 = BORDER_COLOR_ACTIVE;
+            }
+            Interaction::Hovered => {
+                border_color.0 = BORDER_COLOR_HOVER;
+            }
+            Interaction::None => {
+                border_color.0 = BORDER_COLOR_INACTIVE;
+            }
+        }
+    }
+}
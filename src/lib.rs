@@ -11,7 +11,7 @@
 //! fn main() {
 //!     App::new()
 //!         .add_plugins(DefaultPlugins)
-//!         .add_plugins(TextInputPlugin)
+//!         .add_plugins(TextInputPlugin::default())
 //!         .add_systems(Startup, setup)
 //!         .run();
 //! }
@@ -21,22 +21,129 @@
 //!     commands.spawn((NodeBundle::default(), TextInput));
 //! }
 //! ```
+//!
+//! # Picking
+//!
+//! Instead of [`TextInputSettings::focus_on_click`], text inputs can be focused with `bevy_picking` pointer events.
+//! Pointer events on a text input's inner entities bubble up to the text input itself, so an observer on the text
+//! input is enough:
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use bevy_simple_text_input::{TextInput, TextInputInactive};
+//!
+//! fn setup(mut commands: Commands) {
+//!     commands
+//!         .spawn((Node::default(), TextInput, TextInputInactive(true)))
+//!         .observe(
+//!             |trigger: Trigger<Pointer<Click>>, mut query: Query<&mut TextInputInactive>| {
+//!                 if let Ok(mut inactive) = query.get_mut(trigger.entity()) {
+//!                     inactive.0 = false;
+//!                 }
+//!             },
+//!         );
+//! }
+//! ```
+//!
+//! # Events
+//!
+//! The plugin's events, such as [`TextInputSubmitEvent`], can be read with an `EventReader`, which suits systems
+//! that handle every text input in the same way. Each event is also triggered for the text input it is about, so
+//! observers can handle the events of a single text input:
+//!
+//! ```no_run
+//! use bevy::prelude::*;
+//! use bevy_simple_text_input::{TextInput, TextInputSubmitEvent};
+//!
+//! fn setup(mut commands: Commands) {
+//!     commands
+//!         .spawn((Node::default(), TextInput))
+//!         .observe(|trigger: Trigger<TextInputSubmitEvent>| {
+//!             info!("submitted: {}", trigger.event().value);
+//!         });
+//! }
+//! ```
+//!
+//! Observers run after the plugin has sent the events, before systems ordered after [`TextInputSystem`].
+//!
+//! # Game input
+//!
+//! Games usually need to stop reacting to keys such as `WASD` while the player is typing. There are three ways to
+//! do this, which can be combined:
+//!
+//! - Run gameplay systems with the [`any_text_input_focused`] run condition, e.g.
+//!   `movement.run_if(not(any_text_input_focused))`. This works for any input source, including systems that read
+//!   `KeyboardInput` events directly.
+//! - Set [`TextInputSettings::consume_input`], which releases handled keys in `ButtonInput<KeyCode>` for systems
+//!   that run after [`TextInputSystem`]. This only affects those keys, so other shortcuts keep working.
+//! - Add [`TextInputGameInputPlugin`], which hides every key but modifiers from `ButtonInput<KeyCode>` while a text
+//!   input is focused, for all systems in `Update`.
+//!
+//! See the `game_input` example.
+//!
+//! # Custom rendering
+//!
+//! Keyboard editing only depends on [`TextInputSettings`], [`TextInputInactive`], [`TextInputValue`],
+//! [`TextInputCursorPos`] and [`TextInputCursorTimer`]. Spawning those components without the [`TextInput`]
+//! marker gives an entity that is edited like any other text input, but that isn't rendered with `bevy_ui`.
+//! This can be used to drive a custom renderer, for example a world-space `Text2d`.
+
+use std::{borrow::Cow, collections::VecDeque, hash::Hash, ops::Range, time::Duration};
 
 use bevy::{
     asset::load_internal_binary_asset,
-    ecs::{event::EventCursor, system::SystemParam},
+    ecs::{
+        event::EventCursor,
+        query::QueryFilter,
+        schedule::{InternedScheduleLabel, ScheduleLabel},
+        system::SystemParam,
+    },
     input::keyboard::{Key, KeyboardInput},
     prelude::*,
     render::camera::RenderTarget,
     text::{LineBreak, TextLayoutInfo},
     ui::FocusPolicy,
-    window::{PrimaryWindow, WindowRef},
+    utils::{HashMap, HashSet},
+    window::{FileDragAndDrop, PrimaryWindow, WindowRef},
 };
+use unicode_segmentation::{GraphemeCursor, UnicodeSegmentation};
 
 /// A Bevy `Plugin` providing the systems and assets required to make a [`TextInput`] work.
-pub struct TextInputPlugin;
+///
+/// # Example
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// use bevy_simple_text_input::{TextInputNavigationBindings, TextInputPlugin};
+/// App::new().add_plugins(TextInputPlugin {
+///     navigation_bindings: Some(TextInputNavigationBindings::default()),
+///     ..default()
+/// });
+/// ```
+#[derive(Default)]
+pub struct TextInputPlugin {
+    /// The key bindings to use.
+    ///
+    /// If `None`, an existing [`TextInputNavigationBindings`] resource is kept, or the default bindings are used.
+    pub navigation_bindings: Option<TextInputNavigationBindings>,
+    /// The font used to render the cursor.
+    ///
+    /// If `None`, an existing [`TextInputCursorFont`] resource is kept, or the bundled cursor font is used.
+    pub cursor_font: Option<Handle<Font>>,
+    /// The schedule that the plugin's systems are added to.
+    ///
+    /// If `None`, `Update` is used.
+    pub schedule: Option<InternedScheduleLabel>,
+}
 
 /// Label for systems that update text inputs.
+///
+/// These systems only run while [`TextInputEnabled`] is `true`. Within the set, keyboard input is applied first, then
+/// the displayed text is updated, and finally the text is scrolled to follow the cursor once it has been laid out,
+/// which may be in the following frame.
+///
+/// Systems that react to keyboard input should run before this set if they modify a text input, and after it if they
+/// read its value. See [`TextInputPlugin::schedule`] to run the set outside of `Update`.
 #[derive(Debug, PartialEq, Eq, Clone, Hash, SystemSet)]
 pub struct TextInputSystem;
 
@@ -50,42 +157,219 @@ impl Plugin for TextInputPlugin {
             |bytes: &[u8], _path: String| { Font::try_from_bytes(bytes.to_vec()).unwrap() }
         );
 
-        app.init_resource::<TextInputNavigationBindings>()
+        // Resources that users may insert themselves before adding the plugin must only be initialized if absent.
+        match &self.navigation_bindings {
+            Some(bindings) => app.insert_resource(bindings.clone()),
+            None => app.init_resource::<TextInputNavigationBindings>(),
+        };
+
+        match &self.cursor_font {
+            Some(font) => app.insert_resource(TextInputCursorFont(font.clone())),
+            None => app.init_resource::<TextInputCursorFont>(),
+        };
+
+        let schedule = self.schedule.unwrap_or(Update.intern());
+
+        app.init_resource::<TextInputCursorColor>()
+            .init_resource::<TextInputEnabled>()
+            .init_resource::<FocusedTextInput>()
+            .init_resource::<KeyboardInputCursor>()
             .add_event::<TextInputSubmitEvent>()
+            .add_event::<TextInputReadyEvent>()
+            .add_event::<TextInputEmptyBackspaceEvent>()
+            .add_event::<TextInputKeyEvent>()
+            .add_event::<TextInputCancelEvent>()
+            .add_event::<TextInputRejectEvent>()
+            .add_event::<TextInputChangedEvent>()
+            .add_observer(apply_config)
             .add_observer(create)
+            .add_observer(remove)
+            .add_observer(remove_mask_fn)
+            .configure_sets(
+                schedule,
+                TextInputSystem.run_if(resource_equals(TextInputEnabled(true))),
+            )
             .add_systems(
-                Update,
+                schedule,
                 (
+                    focus_on_click.before(keyboard),
                     keyboard,
+                    insert_dropped_files.after(keyboard).before(update_value),
                     update_value.after(keyboard),
-                    blink_cursor,
+                    clear_on_focus
+                        .after(keyboard)
+                        .before(edit_in_place)
+                        .before(update_value),
+                    edit_in_place.after(keyboard),
+                    parse_numeric.after(edit_in_place),
+                    blink_cursor.run_if(any_text_input_focused),
                     show_hide_cursor,
+                    auto_font_size.before(update_style),
                     update_style,
                     update_color,
+                    update_focus_style,
+                    reject_flash.after(keyboard),
                     show_hide_placeholder,
-                    scroll_with_cursor,
+                    fade_placeholder,
+                    scroll_with_cursor.after(update_value),
+                    smooth_scroll.after(scroll_with_cursor),
+                    (
+                        ellipsize_placeholder,
+                        sync_placeholder_padding,
+                        detect_ready,
+                        update_focused.after(keyboard),
+                        send_changed.after(update_value).after(parse_numeric),
+                        trigger_observers
+                            .after(keyboard)
+                            .after(insert_dropped_files)
+                            .after(edit_in_place)
+                            .after(detect_ready)
+                            .after(send_changed),
+                    ),
                 )
                     .in_set(TextInputSystem),
             )
+            .add_systems(
+                schedule,
+                skip_keyboard_input.run_if(resource_equals(TextInputEnabled(false))),
+            )
+            .register_type::<TextInput>()
+            .register_type::<TextInputConfig>()
             .register_type::<TextInputSettings>()
+            .register_type::<TextInputWordStop>()
+            .register_type::<TextInputWordRightStop>()
+            .register_type::<TextInputControlChars>()
+            .register_type::<TextInputAutoCapitalize>()
             .register_type::<TextInputTextFont>()
             .register_type::<TextInputTextColor>()
             .register_type::<TextInputInactive>()
             .register_type::<TextInputCursorTimer>()
+            .register_type::<TextInputCursorBlink>()
+            .register_type::<TextInputShowCursor>()
             .register_type::<TextInputInner>()
             .register_type::<TextInputValue>()
+            .register_type::<TextInputCommitted>()
+            .register_type::<TextInputSubmitHistory>()
+            .register_type::<TextInputKillRing>()
+            .register_type::<TextInputDirty>()
+            .register_type::<TextInputChangeDebounce>()
+            .register_type::<TextInputKeyEvents>()
+            .register_type::<TextInputFocusStyle>()
+            .register_type::<TextInputRejectFlash>()
+            .register_type::<TextInputNoScroll>()
+            .register_type::<TextInputAutoFontSize>()
+            .register_type::<TextInputEditInPlace>()
+            .register_type::<TextInputNumeric>()
+            .register_type::<TextInputNumericValue>()
+            .register_type::<TextInputNumericInvalid>()
             .register_type::<TextInputPlaceholder>()
-            .register_type::<TextInputCursorPos>();
+            .register_type::<TextInputPlaceholderOverflow>()
+            .register_type::<TextInputCursorPos>()
+            .register_type::<TextInputCursorColor>()
+            .register_type::<TextInputEnabled>()
+            .register_type::<FocusedTextInput>();
+
+        #[cfg(feature = "recording")]
+        app.add_systems(
+            schedule,
+            (advance_replays.before(keyboard), record.after(keyboard)).in_set(TextInputSystem),
+        );
+    }
+}
+
+/// A Bevy `Plugin` that hides typed keys from the rest of the app while a text input is focused.
+///
+/// While any text input is active, every key except modifiers and [`TextInputGameInputPlugin::allowed_keys`] is
+/// reset in `ButtonInput<KeyCode>` in `PreUpdate`, so gameplay systems that check e.g. `KeyCode::KeyW` don't react
+/// to typing. A key that is still held when the text input is blurred is only reported again once it is pressed
+/// again. Modifiers are kept, because text input bindings check them. Like [`TextInputSystem`], this doesn't run
+/// while [`TextInputEnabled`] is `false`.
+///
+/// This doesn't affect systems that read `KeyboardInput` events directly. Use [`any_text_input_focused`] for those.
+///
+/// # Example
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// use bevy_simple_text_input::{TextInputGameInputPlugin, TextInputPlugin};
+/// App::new().add_plugins((
+///     TextInputPlugin::default(),
+///     TextInputGameInputPlugin {
+///         allowed_keys: vec![KeyCode::Escape],
+///     },
+/// ));
+/// ```
+#[derive(Default)]
+pub struct TextInputGameInputPlugin {
+    /// Keys that are still reported while a text input is focused, e.g. a key that opens a pause menu.
+    pub allowed_keys: Vec<KeyCode>,
+}
+
+impl Plugin for TextInputGameInputPlugin {
+    fn build(&self, app: &mut App) {
+        let allowed_keys = self.allowed_keys.clone();
+        app.add_systems(
+            PreUpdate,
+            (move |mut key_input: ResMut<ButtonInput<KeyCode>>| {
+                let typed: Vec<KeyCode> = key_input
+                    .get_pressed()
+                    .chain(key_input.get_just_released())
+                    .filter(|key| !is_modifier(**key) && !allowed_keys.contains(key))
+                    .copied()
+                    .collect();
+                for key in typed {
+                    key_input.reset(key);
+                }
+            })
+            .after(bevy::input::InputSystem)
+            .run_if(resource_exists_and_equals(TextInputEnabled(true)))
+            .run_if(any_text_input_focused),
+        );
     }
 }
 
 const CURSOR_HANDLE: Handle<Font> = Handle::weak_from_u128(10482756907980398621);
 
+/// A resource containing the font used to render the text input cursor.
+///
+/// By default, this is a font bundled with this crate. A custom font must have a zero-width `|` glyph, which is
+/// used when the cursor is between two characters, and a `}` glyph, which is used when it is at the end of the text.
+///
+/// Changes only apply to text inputs spawned afterwards, or when their [`TextInputTextFont`] changes.
+///
+/// The plugin will not overwrite an existing resource unless [`TextInputPlugin::cursor_font`] is set.
+#[derive(Resource, Clone)]
+pub struct TextInputCursorFont(pub Handle<Font>);
+
+impl Default for TextInputCursorFont {
+    fn default() -> Self {
+        Self(CURSOR_HANDLE)
+    }
+}
+
+/// A resource containing the color used to render the text input cursor for all text inputs.
+///
+/// If `None` (the default), each text input's cursor uses its [`TextInputTextColor`]. Changing this resource
+/// updates the cursor of every text input, which is useful for app-wide themes such as a high-contrast mode.
+///
+/// The plugin will not overwrite an existing resource.
+#[derive(Resource, Clone, Default, Debug, Reflect)]
+pub struct TextInputCursorColor(pub Option<Color>);
+
+impl TextInputCursorColor {
+    fn resolve(&self, text_color: &TextInputTextColor) -> TextColor {
+        self.0.map(TextColor).unwrap_or(text_color.0)
+    }
+}
+
 /// Marker component for a Text Input entity.
 ///
 /// Add this to a Bevy `NodeBundle`. In addition to its [required components](TextInput#impl-Component-for-TextInput), some other
 /// components may also be spawned with it: [`TextInputCursorPos`].
 ///
+/// Dead keys are combined with the character typed after them where possible, so that typing `´` then `e`
+/// inserts `é`.
+///
 /// # Example
 ///
 /// ```rust
@@ -95,7 +379,7 @@ const CURSOR_HANDLE: Handle<Font> = Handle::weak_from_u128(10482756907980398621)
 ///     commands.spawn((NodeBundle::default(), TextInput));
 /// }
 /// ```
-#[derive(Component, Default)]
+#[derive(Component, Default, Reflect)]
 #[require(
     TextInputSettings,
     TextInputTextFont,
@@ -109,6 +393,154 @@ const CURSOR_HANDLE: Handle<Font> = Handle::weak_from_u128(10482756907980398621)
 )]
 pub struct TextInput;
 
+impl TextInput {
+    /// Creates a [`TextInputBuilder`] for spawning a text input with its components configured all at once.
+    pub fn builder() -> TextInputBuilder {
+        TextInputBuilder::default()
+    }
+}
+
+/// A builder for spawning a fully-configured [`TextInput`].
+///
+/// This replaces the `TextInputBundle` of older versions. Components that aren't set keep their defaults.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_simple_text_input::TextInput;
+/// fn setup(mut commands: Commands) {
+///     commands.spawn(
+///         TextInput::builder()
+///             .value("Hello")
+///             .placeholder("Type something")
+///             .text_color(Color::WHITE)
+///             .build(),
+///     );
+/// }
+/// ```
+#[derive(Default)]
+pub struct TextInputBuilder {
+    value: TextInputValue,
+    text_font: TextInputTextFont,
+    text_color: TextInputTextColor,
+    placeholder: TextInputPlaceholder,
+    settings: TextInputSettings,
+    inactive: TextInputInactive,
+}
+
+impl TextInputBuilder {
+    /// Sets the initial value of the text input.
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = TextInputValue(value.into());
+        self
+    }
+
+    /// Sets the `TextFont` of the text input.
+    pub fn text_font(mut self, text_font: TextFont) -> Self {
+        self.text_font = TextInputTextFont(text_font);
+        self
+    }
+
+    /// Sets the `TextColor` of the text input.
+    pub fn text_color(mut self, text_color: impl Into<TextColor>) -> Self {
+        self.text_color = TextInputTextColor(text_color.into());
+        self
+    }
+
+    /// Sets the placeholder text that is displayed when the text input is empty and not focused.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder.value = placeholder.into();
+        self
+    }
+
+    /// Sets the `TextFont` used to render the placeholder text.
+    pub fn placeholder_text_font(mut self, text_font: TextFont) -> Self {
+        self.placeholder.text_font = Some(text_font);
+        self
+    }
+
+    /// Sets the `TextColor` used to render the placeholder text.
+    pub fn placeholder_text_color(mut self, text_color: impl Into<TextColor>) -> Self {
+        self.placeholder.text_color = Some(text_color.into());
+        self
+    }
+
+    /// Sets the text input's settings.
+    pub fn settings(mut self, settings: TextInputSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+
+    /// Sets whether the text input is initially inactive.
+    pub fn inactive(mut self, inactive: bool) -> Self {
+        self.inactive = TextInputInactive(inactive);
+        self
+    }
+
+    /// Consumes the builder, returning a `Bundle` containing a [`TextInput`] and its configured components.
+    pub fn build(self) -> impl Bundle {
+        (
+            TextInput,
+            self.value,
+            self.text_font,
+            self.text_color,
+            self.placeholder,
+            self.settings,
+            self.inactive,
+        )
+    }
+}
+
+/// A component that configures a text input's font, color, placeholder and settings all at once.
+///
+/// Spawn this instead of [`TextInput`]. When it is added, it is replaced with a [`TextInput`] and the individual
+/// components it contains. Individual components that are spawned alongside it, such as a [`TextInputTextColor`],
+/// take precedence over its fields.
+///
+/// Changing this component after it has been applied has no effect, because it is removed. It is applied like a
+/// [`TextInputBuilder`] converted from it with `From`.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_simple_text_input::{TextInputConfig, TextInputPlaceholder};
+/// fn setup(mut commands: Commands) {
+///     commands.spawn(TextInputConfig {
+///         text_color: TextColor(Color::WHITE),
+///         placeholder: TextInputPlaceholder {
+///             value: "Type something".to_string(),
+///             ..default()
+///         },
+///         ..default()
+///     });
+/// }
+/// ```
+#[derive(Component, Default, Reflect)]
+pub struct TextInputConfig {
+    /// The `TextFont` used by the text input.
+    pub text_font: TextFont,
+    /// The `TextColor` used by the text input.
+    pub text_color: TextColor,
+    /// The placeholder text displayed when the text input is empty and not focused.
+    pub placeholder: TextInputPlaceholder,
+    /// The text input's settings.
+    pub settings: TextInputSettings,
+}
+
+impl From<TextInputConfig> for TextInputBuilder {
+    fn from(config: TextInputConfig) -> Self {
+        Self {
+            text_font: TextInputTextFont(config.text_font),
+            text_color: TextInputTextColor(config.text_color),
+            placeholder: config.placeholder,
+            settings: config.settings,
+            ..default()
+        }
+    }
+}
+
 /// The Bevy `TextColor` that will be used when creating the text input's inner Bevy `TextBundle`.
 #[derive(Component, Default, Reflect)]
 pub struct TextInputTextFont(pub TextFont);
@@ -118,6 +550,8 @@ pub struct TextInputTextFont(pub TextFont);
 pub struct TextInputTextColor(pub TextColor);
 
 /// If true, the text input does not respond to keyboard events and the cursor is hidden.
+///
+/// Text inputs are created with the value given here, unless [`TextInputSettings::autofocus`] is set.
 #[derive(Component, Default, Reflect)]
 pub struct TextInputInactive(pub bool);
 
@@ -126,14 +560,82 @@ pub struct TextInputInactive(pub bool);
 pub struct TextInputCursorTimer {
     /// The timer that blinks the cursor on and off, and resets when the user types.
     pub timer: Timer,
+    /// Additional time that the cursor stays solid after the user types, before it starts blinking again.
+    pub idle_delay: Duration,
+    /// The time taken to fade the cursor in or out at the start of each blink.
+    ///
+    /// If `None`, the cursor is shown and hidden instantly.
+    pub fade: Option<Duration>,
     should_reset: bool,
+    visible: bool,
+    idle_remaining: Duration,
 }
 
 impl Default for TextInputCursorTimer {
     fn default() -> Self {
         Self {
             timer: Timer::from_seconds(0.5, TimerMode::Repeating),
+            idle_delay: Duration::ZERO,
+            fade: None,
             should_reset: false,
+            visible: false,
+            idle_remaining: Duration::ZERO,
+        }
+    }
+}
+
+impl TextInputCursorTimer {
+    /// Returns true if the cursor is currently in the "on" phase of its blink.
+    ///
+    /// This is always false while the text input is inactive.
+    pub fn is_cursor_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Shows the cursor and restarts its blink, as if the user had just typed.
+    ///
+    /// This takes effect the next time the plugin's systems run, and only while the text input is active.
+    pub fn reset(&mut self) {
+        self.should_reset = true;
+    }
+}
+
+/// A component that controls whether the cursor is ever shown.
+///
+/// When `false`, the cursor is hidden and doesn't blink, even while the text input is active. This suits fields that
+/// only display a value. Unlike a read-only text input, which can still be navigated with a visible cursor, there is
+/// no visual indication of the cursor's position at all.
+///
+/// Text inputs without this component show the cursor.
+#[derive(Component, Reflect, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TextInputShowCursor(pub bool);
+
+impl Default for TextInputShowCursor {
+    fn default() -> Self {
+        Self(true)
+    }
+}
+
+/// A component that makes the cursor cycle through a sequence of colors instead of blinking on and off.
+///
+/// The cursor moves to the next color each time its [`TextInputCursorTimer`] finishes, and starts again from the
+/// first color when the user types. [`TextInputCursorTimer::fade`] blends between consecutive colors. Use
+/// `Color::NONE` for a step where the cursor is hidden. An empty sequence blinks as usual.
+///
+/// See the `rainbow_cursor` example.
+#[derive(Component, Default, Reflect, Clone, Debug)]
+pub struct TextInputCursorBlink {
+    /// The colors to cycle through.
+    pub colors: Vec<Color>,
+    step: usize,
+}
+
+impl TextInputCursorBlink {
+    /// Creates a cursor blink that cycles through the given colors.
+    pub fn new(colors: impl Into<Vec<Color>>) -> Self {
+        Self {
+            colors: colors.into(),
+            step: 0,
         }
     }
 }
@@ -145,10 +647,257 @@ pub struct TextInputSettings {
     pub retain_on_submit: bool,
     /// Mask text with the provided character.
     pub mask_character: Option<char>,
+    /// The number of characters at the end of the value that are left unmasked, e.g. the last digits of a card
+    /// number.
+    ///
+    /// See [`TextInputSettings::mask_character`].
+    pub mask_except_last: usize,
+    /// Where the cursor stops when navigating by word.
+    pub word_stop: TextInputWordStop,
+    /// Where the cursor stops when navigating one word to the right.
+    pub word_right_stop: TextInputWordRightStop,
+    /// How control characters in typed or inserted text are handled.
+    pub control_chars: TextInputControlChars,
+    /// Which typed letters are capitalized automatically.
+    pub auto_capitalize: TextInputAutoCapitalize,
+    /// If true, typing a space at the start of the value does nothing.
+    pub trim_leading_spaces: bool,
+    /// If true, typing a space next to whitespace does nothing, so that words are separated by single spaces.
+    pub collapse_spaces: bool,
+    /// The character to insert when space is pressed with shift held, e.g. `'\u{a0}'` for a non-breaking space.
+    ///
+    /// If `None`, a regular space is inserted.
+    pub shift_space: Option<char>,
+    /// If true, the text input's `FocusPolicy` is left untouched when it is created.
+    ///
+    /// By default, it is set to `FocusPolicy::Block` to prevent clicks from registering on UI elements
+    /// underneath the text input.
+    pub preserve_focus_policy: bool,
+    /// If true, the text input becomes inactive after pressing enter.
+    pub blur_on_submit: bool,
+    /// If true, the text input becomes active when it is created, and all other text inputs become inactive.
+    ///
+    /// This takes precedence over the text input's [`TextInputInactive`] component. If several text inputs with
+    /// this setting are created at once, the last one to be created is active.
+    pub autofocus: bool,
+    /// If true, the value is cleared when the text input becomes active, e.g. for a quantity field showing a
+    /// default value that is replaced rather than edited.
+    ///
+    /// If the text input becomes inactive again while its value is still empty, the previous value is restored.
+    pub clear_on_focus: bool,
+    /// If true, pressing enter does nothing while the value is empty or only contains whitespace, e.g. to avoid
+    /// sending blank chat messages.
+    pub ignore_empty_submit: bool,
+    /// If true, the cursor keeps blinking while it is moved, instead of staying visible.
+    pub keep_blinking_on_navigation: bool,
+    /// If true, the text input becomes inactive after pressing escape.
+    ///
+    /// See [`TextInputAction::Cancel`].
+    pub blur_on_cancel: bool,
+    /// If true, text that overflows the text input is clipped and never scrolled to follow the cursor.
+    pub disable_scroll: bool,
+    /// If true, the text input becomes active when it is clicked.
+    ///
+    /// Any other text inputs with this setting enabled will become inactive.
+    pub focus_on_click: bool,
+    /// If set, the text input glides to follow the cursor instead of scrolling instantly.
+    ///
+    /// Higher values scroll faster.
+    pub scroll_smoothing: Option<f32>,
+    /// If true, masked text is shown unmasked while the text input is active.
+    ///
+    /// See [`TextInputSettings::mask_character`].
+    pub reveal_on_focus: bool,
+    /// The maximum number of characters that can be entered.
+    ///
+    /// If the value is set to something longer, it is truncated unless
+    /// [`TextInputSettings::keep_overlong_value`] is true.
+    ///
+    /// The whole value is laid out again whenever it changes, so setting a limit is recommended for text inputs
+    /// that may receive very long values, such as inserted text.
+    pub max_length: Option<usize>,
+    /// If true, a value longer than [`TextInputSettings::max_length`] that is set programmatically is left as-is,
+    /// though no more characters can be typed into it.
+    pub keep_overlong_value: bool,
+    /// If true, keys handled by the text input while it is active are released in `ButtonInput<KeyCode>`, so that
+    /// systems running after [`TextInputSystem`] do not also react to them.
+    ///
+    /// `KeyboardInput` events can't be consumed. Systems reading them directly should use
+    /// [`any_text_input_focused`] instead.
+    pub consume_input: bool,
+    /// If true, the path of a file dropped onto the window is inserted at the cursor while the text input is
+    /// active.
+    ///
+    /// This only has an effect on platforms that support dropping files.
+    pub insert_dropped_files: bool,
+    /// The minimum width of the text input, applied when it is created if its `Node` has no `min_width`.
+    ///
+    /// Without a `width` or `min_width`, an empty text input is only as wide as the cursor, which makes it hard to
+    /// click.
+    pub min_width: Option<Val>,
+}
+
+impl TextInputSettings {
+    fn revealed(&self, inactive: &TextInputInactive) -> bool {
+        self.reveal_on_focus && !inactive.0
+    }
+
+    fn mask(&self, inactive: &TextInputInactive) -> Option<char> {
+        if self.revealed(inactive) {
+            None
+        } else {
+            self.mask_character
+        }
+    }
+}
+
+/// A component containing a function used to mask the text input's value, instead of
+/// [`TextInputSettings::mask_character`].
+///
+/// The function receives the entire value. If the cursor is at the end of the value, it is displayed at the end
+/// of the masked value. Otherwise, it is displayed at the same character index, clamped to the masked value's
+/// length.
+///
+/// Inserting, changing or removing this component updates the displayed value.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_simple_text_input::{TextInput, TextInputMaskFn};
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         TextInput,
+///         // Only show the last four characters
+///         TextInputMaskFn(|value| {
+///             let hidden = value.chars().count().saturating_sub(4);
+///             value
+///                 .chars()
+///                 .enumerate()
+///                 .map(|(i, c)| if i < hidden { '•' } else { c })
+///                 .collect()
+///         }),
+///     ));
+/// }
+/// ```
+#[derive(Component)]
+pub struct TextInputMaskFn(pub fn(&str) -> String);
+
+/// Boundaries at which the `WordLeft` and `WordRight` actions stop.
+///
+/// Words follow Unicode word boundaries, so e.g. each ideograph is a word of its own.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum TextInputWordStop {
+    /// Words are separated only by whitespace.
+    #[default]
+    Whitespace,
+    /// Words are separated by whitespace, and runs of punctuation are treated as separate words.
+    WhitespaceAndPunctuation,
+}
+
+/// What happens to control characters, such as tabs, that are typed or inserted into a text input.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum TextInputControlChars {
+    /// Control characters are removed.
+    #[default]
+    Strip,
+    /// Control characters are replaced with the given character, e.g. a space.
+    Replace(char),
+    /// Control characters are inserted as-is. Most fonts render them as boxes.
+    Keep,
+}
+
+impl TextInputControlChars {
+    fn apply<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        if *self == Self::Keep || !text.chars().any(char::is_control) {
+            return Cow::Borrowed(text);
+        }
+
+        match self {
+            Self::Replace(replacement) => text
+                .chars()
+                .map(|c| if c.is_control() { *replacement } else { c })
+                .collect(),
+            _ => text.chars().filter(|c| !c.is_control()).collect(),
+        }
+    }
+}
+
+/// Which typed letters are capitalized automatically, like on mobile keyboards.
+///
+/// Only typed text is capitalized, not text inserted with [`TextInputAction::InsertText`]. If an automatically
+/// capitalized letter is removed with backspace right away, the letter typed in its place is left as typed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum TextInputAutoCapitalize {
+    /// Nothing is capitalized.
+    #[default]
+    None,
+    /// The first letter of the value and of each sentence, after `.`, `!` or `?` followed by whitespace.
+    Sentences,
+    /// The first letter of each word.
+    Words,
+    /// Every letter.
+    Characters,
+}
+
+impl TextInputAutoCapitalize {
+    // Capitalizes `text`, which is typed after `before`, returning `None` if it is unchanged.
+    fn apply(self, before: &str, text: &str) -> Option<String> {
+        let capitalize_first = match self {
+            Self::None => return None,
+            Self::Characters => {
+                let upper = text.to_uppercase();
+                return (upper != text).then_some(upper);
+            }
+            Self::Words => before.chars().next_back().is_none_or(char::is_whitespace),
+            Self::Sentences => {
+                let trimmed = before.trim_end();
+                trimmed.is_empty()
+                    || (trimmed.len() < before.len() && trimmed.ends_with(['.', '!', '?']))
+            }
+        };
+
+        let mut chars = text.chars();
+        let first = chars.next()?;
+        if !capitalize_first || !first.is_lowercase() {
+            return None;
+        }
+
+        Some(first.to_uppercase().chain(chars).collect())
+    }
+}
+
+/// Where the `WordRight` action moves the cursor to.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum TextInputWordRightStop {
+    /// The cursor moves past the current word and any whitespace after it, to the start of the next word.
+    #[default]
+    NextWordStart,
+    /// The cursor moves to the end of the current word, or of the next word if it is not in one.
+    CurrentWordEnd,
+}
+
+#[derive(PartialEq, Eq)]
+enum CharClass {
+    Whitespace,
+    Punctuation,
+    Word,
+}
+
+impl TextInputWordStop {
+    fn class(&self, c: char) -> CharClass {
+        if c.is_whitespace() {
+            CharClass::Whitespace
+        } else if *self == Self::WhitespaceAndPunctuation && !(c.is_alphanumeric() || c == '_') {
+            CharClass::Punctuation
+        } else {
+            CharClass::Word
+        }
+    }
 }
 
 /// Text navigation actions that can be bound via `TextInputNavigationBindings`.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum TextInputAction {
     /// Moves the cursor one char to the left.
     CharLeft,
@@ -167,30 +916,153 @@ pub enum TextInputAction {
     /// Removes the char right of the cursor.
     DeleteNext,
     /// Triggers a `TextInputSubmitEvent`, optionally clearing the text input.
+    ///
+    /// This is performed at most once per key press, and is not repeated while the key is held.
     Submit,
+    /// Inserts the given text at the cursor.
+    ///
+    /// Control characters are handled first, according to [`TextInputSettings::control_chars`]. Then characters that
+    /// a [`TextInputNumeric`] text input doesn't accept are removed, and finally the text is truncated to fit in
+    /// [`TextInputSettings::max_length`]. The cursor is moved past the text that was actually inserted. Masking only
+    /// affects how the value is displayed, so it is applied afterwards.
+    InsertText(String),
+    /// Copies the value into the text input's [`TextInputCommitted`] component, if it has one.
+    Commit,
+    /// Restores the value from the text input's [`TextInputCommitted`] component, if it has one.
+    Revert,
+    /// Abandons the current edit, triggering a `TextInputCancelEvent`.
+    ///
+    /// This reverts the value like [`TextInputAction::Revert`], and makes the text input inactive if
+    /// [`TextInputSettings::blur_on_cancel`] is set.
+    Cancel,
+    /// Replaces the value with the previous entry in the text input's [`TextInputSubmitHistory`].
+    ///
+    /// Bindings for this action are ignored by text inputs without a history.
+    HistoryPrev,
+    /// Replaces the value with the next entry in the text input's [`TextInputSubmitHistory`].
+    ///
+    /// Bindings for this action are ignored by text inputs without a history.
+    HistoryNext,
+    /// Swaps the chars on either side of the cursor and moves the cursor past them, like emacs' `transpose-chars`.
+    ///
+    /// At the end of the text, the last two chars are swapped instead. This is not bound by default.
+    Transpose,
+    /// Removes the text from the cursor to the end of line. This is not bound by default.
+    ///
+    /// The removed text is added to the text input's [`TextInputKillRing`], if it has one.
+    KillToLineEnd,
+    /// Removes the text from the start of line to the cursor. This is not bound by default.
+    ///
+    /// The removed text is added to the text input's [`TextInputKillRing`], if it has one.
+    KillToLineStart,
+    /// Inserts the most recently killed text from the text input's [`TextInputKillRing`] at the cursor, if it has
+    /// one. This is not bound by default.
+    Yank,
 }
 /// A resource in which key bindings can be specified. Bindings are given as a tuple of (`TextInputAction`, `TextInputBinding`).
 ///
 /// All modifiers must be held when the primary key is pressed to perform the action.
 /// The first matching action in the list will be performed, so a binding that is the same as another with additional
 /// modifier keys should be earlier in the vector to be applied.
-#[derive(Resource)]
+///
+/// A key press that matches a binding only performs the action, and is never also inserted as text, even if the key
+/// is printable. A binding can use [`TextInputAction::InsertText`] to insert text as well.
+///
+/// The default bindings match the physical `Backspace`, `Delete` and `Enter` keys, including their numpad
+/// equivalents, and also match the corresponding logical keys on platforms such as the web where the physical key may
+/// not be reported.
+///
+/// Custom bindings can be inserted before or after adding [`TextInputPlugin`]. The plugin will not overwrite an
+/// existing resource unless [`TextInputPlugin::navigation_bindings`] is set.
+#[derive(Resource, Clone)]
 pub struct TextInputNavigationBindings(pub Vec<(TextInputAction, TextInputBinding)>);
 
 /// A combination of a key and required modifier keys that might trigger a `TextInputAction`.
+#[derive(Clone)]
 pub struct TextInputBinding {
     /// Primary key
-    key: KeyCode,
+    key: TextInputBindingKey,
     /// Required modifier keys
     modifiers: Vec<KeyCode>,
+    /// Whether the action is triggered when the key is pressed or released
+    trigger: TextInputBindingTrigger,
 }
 
 impl TextInputBinding {
-    /// Creates a new `TextInputBinding` from a key and required modifiers.
+    /// Creates a new `TextInputBinding` from a physical key and required modifiers.
     pub fn new(key: KeyCode, modifiers: impl Into<Vec<KeyCode>>) -> Self {
         Self {
-            key,
+            key: TextInputBindingKey::Physical(key),
+            modifiers: modifiers.into(),
+            trigger: TextInputBindingTrigger::Press,
+        }
+    }
+
+    /// Creates a new `TextInputBinding` from a logical key and required modifiers.
+    ///
+    /// The binding will follow the user's keyboard layout rather than the key's physical location.
+    pub fn logical(key: Key, modifiers: impl Into<Vec<KeyCode>>) -> Self {
+        Self {
+            key: TextInputBindingKey::Logical(key),
             modifiers: modifiers.into(),
+            trigger: TextInputBindingTrigger::Press,
+        }
+    }
+
+    /// Makes the binding trigger its action when the key is released instead of when it is pressed.
+    ///
+    /// The modifiers must still be held when the key is released. Pressing the key does nothing, and it is not
+    /// inserted as text.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// use bevy_simple_text_input::{TextInputAction, TextInputBinding, TextInputNavigationBindings};
+    /// let mut bindings = TextInputNavigationBindings::default();
+    /// bindings.0.insert(
+    ///     0,
+    ///     (TextInputAction::Submit, TextInputBinding::new(KeyCode::Enter, []).on_release()),
+    /// );
+    /// ```
+    pub fn on_release(mut self) -> Self {
+        self.trigger = TextInputBindingTrigger::Release;
+        self
+    }
+}
+
+/// When a `TextInputBinding` triggers its action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TextInputBindingTrigger {
+    /// When the key is pressed, and again while it repeats.
+    Press,
+    /// When the key is released.
+    Release,
+}
+
+impl TextInputBindingTrigger {
+    fn matches(&self, input: &KeyboardInput) -> bool {
+        match self {
+            Self::Press => input.state.is_pressed(),
+            Self::Release => !input.state.is_pressed(),
+        }
+    }
+}
+
+/// The primary key of a `TextInputBinding`.
+///
+/// Typed characters are always inserted based on the logical key, but bindings match the physical key by default.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TextInputBindingKey {
+    /// Matches a key by its physical location on the keyboard.
+    Physical(KeyCode),
+    /// Matches a key by the value it produces with the current keyboard layout.
+    Logical(Key),
+}
+
+impl TextInputBindingKey {
+    fn matches(&self, input: &KeyboardInput) -> bool {
+        match self {
+            Self::Physical(key_code) => *key_code == input.key_code,
+            Self::Logical(key) => *key == input.logical_key,
         }
     }
 }
@@ -214,6 +1086,13 @@ impl Default for TextInputNavigationBindings {
             (DeleteNext, TextInputBinding::new(Delete, [])),
             (Submit, TextInputBinding::new(Enter, [])),
             (Submit, TextInputBinding::new(NumpadEnter, [])),
+            (Cancel, TextInputBinding::new(Escape, [])),
+            (HistoryPrev, TextInputBinding::new(ArrowUp, [])),
+            (HistoryNext, TextInputBinding::new(ArrowDown, [])),
+            // Fall back to logical keys in case a platform doesn't report the physical key.
+            (DeletePrev, TextInputBinding::logical(Key::Backspace, [])),
+            (DeleteNext, TextInputBinding::logical(Key::Delete, [])),
+            (Submit, TextInputBinding::logical(Key::Enter, [])),
         ])
     }
 }
@@ -239,623 +1118,5453 @@ impl Default for TextInputNavigationBindings {
             (DeleteNext, TextInputBinding::new(Delete, [])),
             (Submit, TextInputBinding::new(Enter, [])),
             (Submit, TextInputBinding::new(NumpadEnter, [])),
+            (Cancel, TextInputBinding::new(Escape, [])),
+            (HistoryPrev, TextInputBinding::new(ArrowUp, [])),
+            (HistoryNext, TextInputBinding::new(ArrowDown, [])),
+            // Fall back to logical keys in case a platform doesn't report the physical key.
+            (DeletePrev, TextInputBinding::logical(Key::Backspace, [])),
+            (DeleteNext, TextInputBinding::logical(Key::Delete, [])),
+            (Submit, TextInputBinding::logical(Key::Enter, [])),
         ])
     }
 }
 
+/// A resource that enables or disables the plugin's systems in [`TextInputSystem`].
+///
+/// Text inputs are enabled by default. While disabled, keyboard input is ignored and text inputs are not updated,
+/// which can be useful on screens such as a pause menu. Keys pressed while disabled are discarded rather than typed
+/// once the plugin is enabled again.
+///
+/// Text inputs that are spawned or removed while disabled are still set up and cleaned up, because that is done by
+/// observers rather than systems in [`TextInputSystem`]. Their values are shown once the plugin is enabled again.
+///
+/// To tie this to an app state, toggle it when entering and exiting that state:
+///
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_simple_text_input::TextInputEnabled;
+/// #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
+/// enum GameState {
+///     #[default]
+///     Playing,
+///     Paused,
+/// }
+///
+/// App::new()
+///     .add_systems(OnEnter(GameState::Paused), |mut enabled: ResMut<TextInputEnabled>| {
+///         enabled.0 = false;
+///     })
+///     .add_systems(OnExit(GameState::Paused), |mut enabled: ResMut<TextInputEnabled>| {
+///         enabled.0 = true;
+///     });
+/// ```
+///
+/// The plugin will not overwrite an existing resource.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub struct TextInputEnabled(pub bool);
+
+impl Default for TextInputEnabled {
+    fn default() -> Self {
+        Self(true)
+    }
+}
+
+/// A resource containing the text input that is currently active, if any.
+///
+/// This is kept up to date by the plugin whenever [`TextInputInactive`] changes. If multiple text inputs are
+/// active at once, it contains the one that was most recently activated.
+///
+/// If the active text input is despawned, this is cleared, or set to another text input that is still active.
+#[derive(Resource, Default, Debug, PartialEq, Eq, Reflect)]
+pub struct FocusedTextInput(pub Option<Entity>);
+
+// How far `keyboard` has read `KeyboardInput` events. This is a resource rather than a `Local` so that it can be
+// advanced while the plugin is disabled.
+#[derive(Resource, Default)]
+struct KeyboardInputCursor(EventCursor<KeyboardInput>);
+
 /// A component containing the current value of the text input.
 #[derive(Component, Default, Reflect)]
 pub struct TextInputValue(pub String);
 
-/// A component containing the placeholder text that is displayed when the text input is empty and not focused.
-#[derive(Component, Default, Reflect)]
-pub struct TextInputPlaceholder {
-    /// The placeholder text.
-    pub value: String,
-    /// The `TextFont` to use when rendering the placeholder text.
+impl TextInputValue {
+    /// Replaces the value, moving the cursor to the end.
     ///
-    /// If `None`, the text input font will be used.
-    pub text_font: Option<TextFont>,
-    /// The style to use when rendering the placeholder text.
+    /// This is equivalent to assigning to the inner `String`.
+    pub fn set_value(&mut self, value: impl Into<String>) {
+        self.0 = value.into();
+    }
+
+    /// Replaces the value, keeping the cursor where it is.
     ///
-    /// If `None`, the text input color will be used with alpha value of `0.25`.
-    pub text_color: Option<TextColor>,
+    /// If the new value is shorter, the cursor is moved to its end.
+    pub fn set_value_keep_cursor(
+        &mut self,
+        value: impl Into<String>,
+        cursor_pos: &mut Mut<TextInputCursorPos>,
+    ) {
+        self.0 = value.into();
+        cursor_pos.set_changed();
+    }
+
+    /// Appends `text` to the end of the value.
+    ///
+    /// Changing the value normally moves the cursor to the end. This only does so if the cursor was already at
+    /// the end, so that appending in the background does not disturb a user editing the middle of the text.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// use bevy_simple_text_input::{TextInputCursorPos, TextInputValue};
+    /// fn append_log(mut query: Query<(&mut TextInputValue, &mut TextInputCursorPos)>) {
+    ///     for (mut value, mut cursor_pos) in &mut query {
+    ///         value.append(" more text", &mut cursor_pos);
+    ///     }
+    /// }
+    /// ```
+    pub fn append(&mut self, text: &str, cursor_pos: &mut Mut<TextInputCursorPos>) {
+        if cursor_pos.0 < self.0.chars().count() {
+            cursor_pos.set_changed();
+        }
+
+        self.0.push_str(text);
+    }
+
+    /// Returns the number of chars in the value, which is the unit used by [`TextInputCursorPos`].
+    pub fn char_count(&self) -> usize {
+        self.0.chars().count()
+    }
+
+    /// Returns the number of words in the value, following Unicode word boundaries.
+    ///
+    /// Punctuation is not counted, and each ideograph counts as a word.
+    ///
+    /// ```rust
+    /// use bevy_simple_text_input::TextInputValue;
+    /// assert_eq!(TextInputValue("  hello\u{3000}wörld ".into()).word_count(), 2);
+    /// ```
+    pub fn word_count(&self) -> usize {
+        self.0.unicode_words().count()
+    }
+
+    /// Converts a char index, like [`TextInputCursorPos`], into a byte offset into the value.
+    ///
+    /// An index past the end of the value gives the length of the value in bytes.
+    ///
+    /// ```rust
+    /// use bevy_simple_text_input::TextInputValue;
+    /// let value = TextInputValue("héllo".into());
+    /// assert_eq!(value.char_to_byte(2), 3);
+    /// assert_eq!(value.char_to_byte(10), 6);
+    /// ```
+    pub fn char_to_byte(&self, char_index: usize) -> usize {
+        byte_pos(&self.0, char_index)
+    }
+
+    /// Converts a byte offset into the value into a char index, like [`TextInputCursorPos`].
+    ///
+    /// An offset inside a multi-byte char is moved back to the start of that char, and an offset past the end of
+    /// the value gives the number of chars. To place the cursor at a byte offset, e.g. one from an external editor,
+    /// set it to `TextInputCursorPos(value.byte_to_char(offset))`.
+    ///
+    /// ```rust
+    /// use bevy_simple_text_input::TextInputValue;
+    /// let value = TextInputValue("héllo".into());
+    /// assert_eq!(value.byte_to_char(3), 2);
+    /// assert_eq!(value.byte_to_char(2), 1);
+    /// assert_eq!(value.byte_to_char(10), 5);
+    /// ```
+    pub fn byte_to_char(&self, byte_index: usize) -> usize {
+        self.0
+            .char_indices()
+            .take_while(|(pos, c)| pos + c.len_utf8() <= byte_index)
+            .count()
+    }
 }
 
-#[derive(Component, Reflect)]
-struct TextInputPlaceholderInner;
+/// Marker component for a text input that is edited in place, e.g. a cell in a table that becomes editable when
+/// it is clicked.
+///
+/// The text input becomes active when this is added, and when it becomes inactive again, its value is submitted,
+/// just like when enter is pressed. The value is never cleared when submitted this way. To also retain it when enter
+/// is pressed, set [`TextInputSettings::retain_on_submit`].
+///
+/// See the `edit_in_place` example.
+#[derive(Component, Default, Reflect)]
+pub struct TextInputEditInPlace;
 
-/// A component containing the current text cursor position.
+/// Marker component for a text input whose text is never scrolled to follow the cursor.
+///
+/// Unlike [`TextInputSettings::disable_scroll`], the inner text's layout is left exactly as it was created, which
+/// is useful for text inputs that are sized to always fit their value.
 #[derive(Component, Default, Reflect)]
-pub struct TextInputCursorPos(pub usize);
+pub struct TextInputNoScroll;
 
-#[derive(Component, Reflect)]
-struct TextInputInner;
+/// A component that shrinks a text input's font size so that its value fits the width of the text input.
+///
+/// The [`TextInputTextFont`] font size is adjusted between `min` and `max` whenever the text or the width of the text
+/// input changes. Shrinking the text takes precedence over scrolling: the text only scrolls once the font size
+/// reaches `min` and the value still doesn't fit.
+///
+/// This is meant for text inputs with a fixed width. The text input's width must not depend on the size of its
+/// text, or the two will never settle.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+pub struct TextInputAutoFontSize {
+    /// The smallest font size to use.
+    pub min: f32,
+    /// The largest font size to use, when the value fits.
+    pub max: f32,
+}
 
-/// An event that is fired when the user presses the enter key.
-#[derive(Event)]
-pub struct TextInputSubmitEvent {
-    /// The text input that triggered the event.
-    pub entity: Entity,
-    /// The string contained in the text input at the time of the event.
-    pub value: String,
+/// A component that changes a text input's `BorderColor` when it becomes active or inactive.
+///
+/// This is not added to text inputs by default.
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[require(BorderColor)]
+pub struct TextInputFocusStyle {
+    /// The border color while the text input is active.
+    pub active: Color,
+    /// The border color while the text input is inactive.
+    pub inactive: Color,
 }
 
-/// A convenience parameter for dealing with a text input's inner Bevy `Text` entity.
-#[derive(SystemParam)]
-struct InnerText<'w, 's> {
-    text_query: Query<'w, 's, (), With<TextInputInner>>,
-    children_query: Query<'w, 's, &'static Children>,
+/// A component that briefly flashes a text input's `BorderColor` when typed or inserted text is rejected.
+///
+/// Text is rejected when a [`TextInputNumeric`] text input doesn't accept some of its characters, or when it would
+/// exceed [`TextInputSettings::max_length`]. The border changes to `color` and fades back to its previous color over
+/// `duration`. A [`TextInputRejectEvent`] is sent whether or not this component is present.
+///
+/// This is not added to text inputs by default.
+#[derive(Component, Clone, Debug, Reflect)]
+#[require(BorderColor)]
+pub struct TextInputRejectFlash {
+    /// The border color at the start of the flash.
+    pub color: Color,
+    /// How long the border takes to fade back to its previous color.
+    pub duration: Duration,
+    remaining: Duration,
+    restore: Option<Color>,
 }
-impl InnerText<'_, '_> {
-    fn inner_entity(&self, entity: Entity) -> Option<Entity> {
-        self.children_query
-            .iter_descendants(entity)
-            .find(|descendant_entity| self.text_query.get(*descendant_entity).is_ok())
+
+impl TextInputRejectFlash {
+    /// Creates a flash with the given color and duration.
+    pub fn new(color: Color, duration: Duration) -> Self {
+        Self {
+            color,
+            duration,
+            remaining: Duration::ZERO,
+            restore: None,
+        }
+    }
+
+    /// Starts the flash, as if text had just been rejected.
+    pub fn start(&mut self) {
+        self.remaining = self.duration;
     }
 }
 
-fn keyboard(
-    key_input: Res<ButtonInput<KeyCode>>,
-    input_events: Res<Events<KeyboardInput>>,
-    mut input_reader: Local<EventCursor<KeyboardInput>>,
-    mut text_input_query: Query<(
-        Entity,
-        &TextInputSettings,
-        &TextInputInactive,
-        &mut TextInputValue,
-        &mut TextInputCursorPos,
-        &mut TextInputCursorTimer,
-    )>,
-    mut submit_writer: EventWriter<TextInputSubmitEvent>,
-    navigation: Res<TextInputNavigationBindings>,
-) {
-    if input_reader.clone().read(&input_events).next().is_none() {
-        return;
+/// A component that restricts a text input to numbers.
+///
+/// Only digits, `-` and `.` can be typed or inserted. When the value is submitted, it is parsed, clamped to `min`
+/// and `max`, and stored in [`TextInputNumericValue`]. If the value is retained and had to be clamped, it is
+/// replaced with the clamped number. What happens when the value isn't a valid number depends on `on_invalid`.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_simple_text_input::{TextInput, TextInputNumeric, TextInputNumericValue};
+/// fn setup(mut commands: Commands) {
+///     commands.spawn((
+///         TextInput,
+///         TextInputNumeric {
+///             min: Some(0.0),
+///             max: Some(100.0),
+///             ..default()
+///         },
+///     ));
+/// }
+///
+/// fn read(query: Query<&TextInputNumericValue, Changed<TextInputNumericValue>>) {
+///     for value in &query {
+///         info!("{:?}", value.0);
+///     }
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[require(TextInputNumericValue)]
+pub struct TextInputNumeric {
+    /// The smallest number that can be submitted.
+    pub min: Option<f64>,
+    /// The largest number that can be submitted.
+    pub max: Option<f64>,
+    /// What happens when a value that isn't a valid number is submitted.
+    pub on_invalid: TextInputNumericInvalid,
+}
+
+/// What happens when a value that isn't a valid number is submitted to a [`TextInputNumeric`] text input.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum TextInputNumericInvalid {
+    /// The value is submitted, and [`TextInputNumericValue`] is set to `None`.
+    #[default]
+    Clear,
+    /// The value is submitted, but [`TextInputNumericValue`] keeps the last valid number. If the value is retained,
+    /// it is replaced with that number.
+    KeepLast,
+    /// Pressing enter does nothing. Values that are submitted in other ways, e.g. by [`TextInputEditInPlace`], are
+    /// handled like [`TextInputNumericInvalid::KeepLast`].
+    Reject,
+}
+
+impl TextInputNumeric {
+    fn accepts(c: char) -> bool {
+        c.is_ascii_digit() || c == '-' || c == '.'
     }
 
-    // collect actions that have all required modifiers held
-    let valid_actions = navigation
-        .0
-        .iter()
-        .filter(|(_, TextInputBinding { modifiers, .. })| {
-            modifiers.iter().all(|m| key_input.pressed(*m))
-        })
-        .map(|(action, TextInputBinding { key, .. })| (*key, action));
+    fn parse(text: &str) -> Option<f64> {
+        text.trim().parse::<f64>().ok().filter(|n| n.is_finite())
+    }
 
-    for (input_entity, settings, inactive, mut text_input, mut cursor_pos, mut cursor_timer) in
-        &mut text_input_query
-    {
-        if inactive.0 {
-            continue;
+    /// Clamps a number to `min` and `max`.
+    pub fn clamp(&self, number: f64) -> f64 {
+        let number = self.min.map_or(number, |min| number.max(min));
+        self.max.map_or(number, |max| number.min(max))
+    }
+
+    /// Adds `delta` to the number in `value`, clamps it, and replaces the value with the result, e.g. for the
+    /// buttons of a stepper.
+    ///
+    /// A value that isn't a valid number is treated as `min`, or as zero if there is no minimum. Returns the new
+    /// number.
+    ///
+    /// ```
+    /// use bevy_simple_text_input::{TextInputNumeric, TextInputValue};
+    /// let numeric = TextInputNumeric {
+    ///     min: Some(0.0),
+    ///     max: Some(10.0),
+    ///     ..Default::default()
+    /// };
+    /// let mut value = TextInputValue("9".into());
+    ///
+    /// assert_eq!(numeric.step(&mut value, 1.0), 10.0);
+    /// assert_eq!(numeric.step(&mut value, 1.0), 10.0);
+    /// assert_eq!(value.0, "10");
+    ///
+    /// value.0 = "oops".into();
+    /// assert_eq!(numeric.step(&mut value, -1.0), 0.0);
+    /// ```
+    pub fn step(&self, value: &mut TextInputValue, delta: f64) -> f64 {
+        let current = Self::parse(&value.0).unwrap_or_else(|| self.min.unwrap_or(0.0));
+        let number = self.clamp(current + delta);
+
+        let text = number.to_string();
+        if value.0 != text {
+            value.0 = text;
         }
 
-        let mut submitted_value = None;
+        number
+    }
+}
 
-        for input in input_reader.clone().read(&input_events) {
-            if !input.state.is_pressed() {
-                continue;
-            };
+/// A component containing the number that was last submitted to a [`TextInputNumeric`] text input.
+///
+/// This is `None` if nothing was submitted yet, or if the submitted value was not a valid number.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+pub struct TextInputNumericValue(pub Option<f64>);
 
-            let pos = cursor_pos.bypass_change_detection().0;
+/// A component containing the last committed value of a text input.
+///
+/// Adding this to a text input allows edits to be reverted, e.g. when a form is cancelled. The value is
+/// committed on [`TextInputAction::Submit`] and [`TextInputAction::Commit`], and restored on
+/// [`TextInputAction::Revert`] and [`TextInputAction::Cancel`], which is bound to `Escape` by default.
+#[derive(Component, Default, Reflect, Clone)]
+pub struct TextInputCommitted(pub String);
 
-            if let Some((_, action)) = valid_actions
-                .clone()
-                .find(|(key, _)| *key == input.key_code)
-            {
-                use TextInputAction::*;
-                let mut timer_should_reset = true;
-                match action {
-                    CharLeft => cursor_pos.0 = cursor_pos.0.saturating_sub(1),
-                    CharRight => cursor_pos.0 = (cursor_pos.0 + 1).min(text_input.0.len()),
-                    LineStart => cursor_pos.0 = 0,
-                    LineEnd => cursor_pos.0 = text_input.0.len(),
-                    WordLeft => {
-                        cursor_pos.0 = text_input
-                            .0
-                            .char_indices()
-                            .rev()
-                            .skip(text_input.0.len() - cursor_pos.0 + 1)
-                            .skip_while(|c| c.1.is_ascii_whitespace())
-                            .find(|c| c.1.is_ascii_whitespace())
-                            .map(|(ix, _)| ix + 1)
-                            .unwrap_or(0)
-                    }
-                    WordRight => {
-                        cursor_pos.0 = text_input
-                            .0
-                            .char_indices()
-                            .skip(cursor_pos.0)
-                            .skip_while(|c| !c.1.is_ascii_whitespace())
-                            .find(|c| !c.1.is_ascii_whitespace())
-                            .map(|(ix, _)| ix)
-                            .unwrap_or(text_input.0.len())
-                    }
-                    DeletePrev => {
-                        if pos > 0 {
-                            cursor_pos.0 -= 1;
-                            text_input.0 = remove_char_at(&text_input.0, cursor_pos.0);
-                        }
-                    }
-                    DeleteNext => {
-                        if pos < text_input.0.len() {
-                            text_input.0 = remove_char_at(&text_input.0, cursor_pos.0);
+impl TextInputCommitted {
+    /// Copies the current value into this component.
+    pub fn commit(&mut self, value: &TextInputValue) {
+        self.0.clone_from(&value.0);
+    }
 
-                            // Ensure that the cursor isn't reset
-                            cursor_pos.set_changed();
-                        }
-                    }
-                    Submit => {
-                        if settings.retain_on_submit {
-                            submitted_value = Some(text_input.0.clone());
-                        } else {
-                            submitted_value = Some(std::mem::take(&mut text_input.0));
-                            cursor_pos.0 = 0;
-                        };
-                        timer_should_reset = false;
-                    }
-                }
+    /// Replaces the current value with this component's value, moving the cursor to the end.
+    pub fn revert(&self, value: &mut TextInputValue) {
+        value.0.clone_from(&self.0);
+    }
+}
 
-                cursor_timer.should_reset |= timer_should_reset;
-                continue;
-            }
+/// A component that records whether the user has edited a text input, e.g. to show an unsaved changes indicator.
+///
+/// This is set to true when the user changes the value by typing, deleting, pasting or dropping a file. It is reset
+/// to false by [`TextInputAction::Submit`] and [`TextInputAction::Commit`], and by [`TextInputAction::Revert`] and
+/// [`TextInputAction::Cancel`] when the text input has a [`TextInputCommitted`] value to restore. Apps can also
+/// reset it themselves, e.g. after saving. Setting [`TextInputValue`] programmatically doesn't change it.
+#[derive(Component, Default, Reflect, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TextInputDirty(pub bool);
+
+/// A component containing previously submitted values of a text input, which can be recalled like a shell history.
+///
+/// Values are added when [`TextInputAction::Submit`] is performed, and recalled with [`TextInputAction::HistoryPrev`]
+/// and [`TextInputAction::HistoryNext`], which are bound to `ArrowUp` and `ArrowDown` by default. These bindings are
+/// ignored by text inputs without this component, so the keys are left to other bindings and aren't consumed by
+/// [`TextInputSettings::consume_input`]. Recalling an entry
+/// replaces the current value and moves the cursor to the end. Moving past the newest entry restores the value that
+/// was being edited before recalling started.
+///
+/// ```
+/// # use bevy_simple_text_input::TextInputSubmitHistory;
+/// let mut history = TextInputSubmitHistory::new(10);
+/// history.push("first");
+/// history.push("second");
+///
+/// assert_eq!(history.recall_prev("draft"), Some("second"));
+/// assert_eq!(history.recall_prev("second"), Some("first"));
+/// assert_eq!(history.recall_next(), Some("second"));
+/// assert_eq!(history.recall_next(), Some("draft"));
+/// assert_eq!(history.recall_next(), None);
+/// ```
+#[derive(Component, Reflect, Clone, Debug)]
+pub struct TextInputSubmitHistory {
+    /// The submitted values, from oldest to newest.
+    pub entries: VecDeque<String>,
+    /// The maximum number of entries to keep. The oldest entries are removed first.
+    pub max_entries: usize,
+    position: Option<usize>,
+    draft: String,
+}
 
-            match input.logical_key {
-                Key::Space => {
-                    let byte_pos = byte_pos(&text_input.0, pos);
-                    text_input.0.insert(byte_pos, ' ');
-                    cursor_pos.0 += 1;
+impl Default for TextInputSubmitHistory {
+    fn default() -> Self {
+        Self::new(100)
+    }
+}
 
-                    cursor_timer.should_reset = true;
-                }
-                Key::Character(ref s) => {
-                    let byte_pos = byte_pos(&text_input.0, pos);
-                    text_input.0.insert_str(byte_pos, s.as_str());
+impl TextInputSubmitHistory {
+    /// Creates an empty history that keeps up to `max_entries` values.
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            entries: VecDeque::new(),
+            max_entries,
+            position: None,
+            draft: String::new(),
+        }
+    }
 
-                    cursor_pos.0 += 1;
+    /// Adds a value to the history and stops recalling.
+    ///
+    /// Empty values and values that are the same as the newest entry are ignored.
+    pub fn push(&mut self, value: impl Into<String>) {
+        self.position = None;
 
-                    cursor_timer.should_reset = true;
-                }
-                _ => (),
-            }
+        let value = value.into();
+        if value.is_empty() || self.entries.back() == Some(&value) {
+            return;
         }
 
-        if let Some(value) = submitted_value {
-            submit_writer.send(TextInputSubmitEvent {
-                entity: input_entity,
-                value,
-            });
+        self.entries.push_back(value);
+        while self.entries.len() > self.max_entries {
+            self.entries.pop_front();
         }
     }
 
-    input_reader.clear(&input_events);
-}
-
-fn update_value(
-    mut input_query: Query<
-        (
-            Entity,
-            Ref<TextInputValue>,
-            &TextInputSettings,
-            &mut TextInputCursorPos,
-        ),
-        Or<(Changed<TextInputValue>, Changed<TextInputCursorPos>)>,
-    >,
-    inner_text: InnerText,
-    mut writer: TextUiWriter,
-) {
-    for (entity, text_input, settings, mut cursor_pos) in &mut input_query {
-        let Some(inner) = inner_text.inner_entity(entity) else {
-            continue;
+    /// Moves to the previous (older) entry and returns it.
+    ///
+    /// `current` is the value being edited, and is kept so that it can be restored by [`Self::recall_next`].
+    /// Returns `None` if there is no older entry.
+    pub fn recall_prev(&mut self, current: &str) -> Option<&str> {
+        let position = match self.position {
+            None => {
+                let last = self.entries.len().checked_sub(1)?;
+                self.draft = current.to_string();
+                last
+            }
+            Some(position) => position.checked_sub(1)?,
         };
 
-        // Reset the cursor to the end of the input when the value is changed by
-        // a user manipulating the value component.
-        if text_input.is_changed() && !cursor_pos.is_changed() {
-            cursor_pos.0 = text_input.0.chars().count();
-        }
+        self.position = Some(position);
+        self.entries.get(position).map(String::as_str)
+    }
+
+    /// Moves to the next (newer) entry and returns it.
+    ///
+    /// Moving past the newest entry returns the value that was being edited when recalling started. Returns `None`
+    /// if the history is not being recalled.
+    pub fn recall_next(&mut self) -> Option<&str> {
+        let position = self.position? + 1;
 
-        if cursor_pos.is_changed() {
-            cursor_pos.0 = cursor_pos.0.clamp(0, text_input.0.chars().count());
+        if position < self.entries.len() {
+            self.position = Some(position);
+            self.entries.get(position).map(String::as_str)
+        } else {
+            self.position = None;
+            Some(&self.draft)
         }
+    }
+}
 
-        let values = get_section_values(
-            &masked_value(&text_input.0, settings.mask_character),
-            cursor_pos.0,
-        );
+/// A component containing text removed by [`TextInputAction::KillToLineEnd`] and [`TextInputAction::KillToLineStart`],
+/// which can be inserted again with [`TextInputAction::Yank`].
+///
+/// This works independently of the system clipboard, like the kill ring in emacs. None of these actions are bound by
+/// default, so bindings must be added to [`TextInputNavigationBindings`] to use it.
+///
+/// ```
+/// # use bevy_simple_text_input::TextInputKillRing;
+/// let mut kill_ring = TextInputKillRing::new(2);
+/// kill_ring.push("one");
+/// kill_ring.push("two");
+/// kill_ring.push("three");
+///
+/// assert_eq!(kill_ring.last(), Some("three"));
+/// assert_eq!(kill_ring.entries.len(), 2);
+/// ```
+#[derive(Component, Reflect, Clone, Debug)]
+pub struct TextInputKillRing {
+    /// The killed text, from oldest to newest.
+    pub entries: VecDeque<String>,
+    /// The maximum number of entries to keep. The oldest entries are removed first.
+    pub max_entries: usize,
+}
 
-        *writer.text(inner, 0) = values.0;
-        *writer.text(inner, 1) = values.1;
-        *writer.text(inner, 2) = values.2;
+impl Default for TextInputKillRing {
+    fn default() -> Self {
+        Self::new(10)
     }
 }
 
-fn scroll_with_cursor(
-    mut inner_text_query: Query<
-        (
-            &TextLayoutInfo,
-            &mut Node,
-            &ComputedNode,
-            &Parent,
-            Option<&TargetCamera>,
-        ),
-        (With<TextInputInner>, Changed<TextLayoutInfo>),
-    >,
-    mut style_query: Query<(&ComputedNode, &mut Node), Without<TextInputInner>>,
-    camera_query: Query<&Camera>,
-    window_query: Query<&Window>,
-    primary_window_query: Query<&Window, With<PrimaryWindow>>,
-) {
-    for (layout, mut style, child_node, parent, target_camera) in inner_text_query.iter_mut() {
-        let Ok((parent_node, mut parent_style)) = style_query.get_mut(parent.get()) else {
-            continue;
-        };
+impl TextInputKillRing {
+    /// Creates an empty kill ring that keeps up to `max_entries` values.
+    pub fn new(max_entries: usize) -> Self {
+        Self {
+            entries: VecDeque::new(),
+            max_entries,
+        }
+    }
 
-        match layout.glyphs.last().map(|g| g.span_index) {
-            // no text -> do nothing
-            None => continue,
-            // if cursor is at the end, position at FlexEnd so newly typed text does not take a frame to move into view
-            Some(1) => {
-                style.left = Val::Auto;
-                parent_style.justify_content = JustifyContent::FlexEnd;
-                continue;
-            }
-            _ => (),
+    /// Adds killed text to the kill ring. Empty text is ignored.
+    pub fn push(&mut self, text: impl Into<String>) {
+        let text = text.into();
+        if text.is_empty() {
+            return;
         }
 
-        // if cursor is in the middle, we use FlexStart + `left` px for consistent behaviour when typing the middle
-        let child_size = child_node.size().x;
-        let parent_size = parent_node.size().x;
+        self.entries.push_back(text);
+        while self.entries.len() > self.max_entries {
+            self.entries.pop_front();
+        }
+    }
 
-        let Some(cursor_pos) = layout
-            .glyphs
-            .iter()
-            .find(|g| g.span_index == 1)
-            .map(|p| p.position.x)
-        else {
-            continue;
+    /// Returns the most recently killed text.
+    pub fn last(&self) -> Option<&str> {
+        self.entries.back().map(String::as_str)
+    }
+}
+
+/// An action recorded by [`TextInputRecording`].
+#[cfg(feature = "recording")]
+#[derive(Clone, Debug)]
+pub struct TextInputRecordedAction {
+    /// The time since the recording started.
+    pub time: Duration,
+    /// The action performed. Typed text is recorded as [`TextInputAction::InsertText`].
+    pub action: TextInputAction,
+}
+
+/// A component that records the actions applied to a text input, so that they can be replayed later.
+///
+/// Every action that the text input handles is recorded along with the time since the component was added. Typed
+/// text is recorded as it was inserted, after dead keys and [`TextInputSettings::auto_capitalize`] were applied.
+///
+/// This is not added to text inputs by default, and requires the `recording` feature.
+///
+/// # Example
+///
+/// ```
+/// # use std::time::Duration;
+/// use bevy_simple_text_input::{
+///     TextInputAction, TextInputRecordedAction, TextInputRecording,
+/// };
+/// let mut recording = TextInputRecording::default();
+/// recording.entries.push(TextInputRecordedAction {
+///     time: Duration::from_millis(100),
+///     action: TextInputAction::InsertText("hi".to_string()),
+/// });
+///
+/// let replay = recording.replay();
+/// assert_eq!(replay.entries.len(), 1);
+/// assert!(!replay.is_finished());
+/// ```
+#[cfg(feature = "recording")]
+#[derive(Component, Clone, Debug, Default)]
+#[require(TextInputKeyEvents)]
+pub struct TextInputRecording {
+    /// The recorded actions, from oldest to newest.
+    pub entries: Vec<TextInputRecordedAction>,
+    elapsed: Duration,
+}
+
+#[cfg(feature = "recording")]
+impl TextInputRecording {
+    /// Creates a [`TextInputReplay`] that applies the recorded actions with the same timing.
+    pub fn replay(&self) -> TextInputReplay {
+        TextInputReplay::new(self.entries.iter().cloned())
+    }
+}
+
+/// A component that applies previously recorded actions to a text input.
+///
+/// Each action is applied once its time has passed since the component was added, as if the key bound to it had
+/// been pressed. Actions are applied even while the text input is inactive.
+///
+/// Requires the `recording` feature.
+#[cfg(feature = "recording")]
+#[derive(Component, Clone, Debug, Default)]
+pub struct TextInputReplay {
+    /// The actions that have not been applied yet, from oldest to newest.
+    pub entries: VecDeque<TextInputRecordedAction>,
+    elapsed: Duration,
+}
+
+#[cfg(feature = "recording")]
+impl TextInputReplay {
+    /// Creates a replay of the given actions.
+    pub fn new(entries: impl IntoIterator<Item = TextInputRecordedAction>) -> Self {
+        Self {
+            entries: entries.into_iter().collect(),
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    /// Returns `true` if every action has been applied.
+    pub fn is_finished(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    // Removes the actions whose time has passed.
+    fn take_due(&mut self) -> Vec<TextInputAction> {
+        let due = self
+            .entries
+            .iter()
+            .take_while(|entry| entry.time <= self.elapsed)
+            .count();
+
+        self.entries
+            .drain(..due)
+            .map(|entry| entry.action)
+            .collect()
+    }
+}
+
+/// A component containing the placeholder text that is displayed when the text input is empty and not focused.
+#[derive(Component, Default, Reflect)]
+pub struct TextInputPlaceholder {
+    /// The placeholder text.
+    pub value: String,
+    /// The `TextFont` to use when rendering the placeholder text.
+    ///
+    /// If `None`, the text input font will be used.
+    pub text_font: Option<TextFont>,
+    /// The style to use when rendering the placeholder text.
+    ///
+    /// If `None`, the text input color will be used with alpha value of `0.25`.
+    pub text_color: Option<TextColor>,
+    /// The time taken to fade the placeholder text in or out.
+    ///
+    /// If `None`, the placeholder text is shown and hidden instantly.
+    pub fade_duration: Option<Duration>,
+    /// If true, the placeholder text is also displayed while the text input is active and empty.
+    ///
+    /// The cursor is displayed at the start of the placeholder text.
+    pub show_when_active: bool,
+    /// What happens to placeholder text that is too long to fit in the text input.
+    pub overflow: TextInputPlaceholderOverflow,
+}
+
+/// What happens to placeholder text that is too long to fit in the text input.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum TextInputPlaceholderOverflow {
+    /// The placeholder text extends past the edge of the text input.
+    #[default]
+    Visible,
+    /// The placeholder text is clipped at the edge of the text input's content area.
+    Clip,
+    /// The placeholder text wraps onto multiple lines, which may extend below the text input.
+    Wrap,
+    /// The placeholder text is shortened to fit in the text input's content area, ending with `…`.
+    Ellipsis,
+}
+
+impl TextInputPlaceholder {
+    fn is_visible(&self, value: &TextInputValue, inactive: &TextInputInactive) -> bool {
+        value.0.is_empty() && (inactive.0 || self.show_when_active)
+    }
+}
+
+#[derive(Component, Reflect)]
+struct TextInputPlaceholderInner;
+
+// Marks the placeholder of a text input whose placeholder overflow is `Ellipsis`.
+#[derive(Component)]
+struct TextInputPlaceholderEllipsis {
+    text_input: Entity,
+}
+
+// Marks the container that clips a text input's placeholder, which copies the text input's padding.
+#[derive(Component)]
+struct TextInputPlaceholderContainer;
+
+/// A component containing the current text cursor position.
+///
+/// The position is preserved when the text input becomes inactive and active again. It is only moved
+/// to the end of the value when [`TextInputValue`] is changed without also changing this component.
+/// When both are changed in the same frame, the new position is kept, clamped to the length of the value.
+///
+/// The position is counted in chars. A position past the end of the value is treated as the end, and a position in
+/// the middle of a grapheme cluster, such as between a letter and its combining accent, is moved to the start of
+/// the cluster when the text input next updates.
+#[derive(Component, Default, Reflect)]
+pub struct TextInputCursorPos(pub usize);
+
+/// Marker component for the Bevy `Text` entity that displays a text input's value.
+///
+/// This is normally spawned by the plugin, but a custom entity can be provided instead, e.g. to render the text
+/// with custom effects. It must be a child of the text input with a `Text` and exactly three `TextSpan` children
+/// containing the text before the cursor, the cursor, and the text after the cursor. The plugin drives the contents,
+/// fonts and colors of these spans.
+///
+/// The custom entity must already be a child when the text input is created, so add [`TextInput`] last. Text
+/// inputs with a custom inner entity do not scroll to follow the cursor.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_simple_text_input::{TextInput, TextInputInner};
+/// fn setup(mut commands: Commands) {
+///     commands
+///         .spawn(Node::default())
+///         .with_children(|parent| {
+///             parent
+///                 .spawn((Text::default(), TextInputInner))
+///                 .with_children(|parent| {
+///                     parent.spawn(TextSpan::default());
+///                     parent.spawn(TextSpan::default());
+///                     parent.spawn(TextSpan::default());
+///                 });
+///         })
+///         .insert(TextInput);
+/// }
+/// ```
+#[derive(Component, Reflect)]
+pub struct TextInputInner;
+
+#[derive(Component)]
+struct TextInputInnerReady;
+
+#[derive(Component, Default)]
+struct TextInputInnerScroll {
+    target: Option<f32>,
+    speed: f32,
+    /// Whether the cursor is after the last character of the displayed value.
+    cursor_at_end: bool,
+}
+
+/// An event that is fired when the user presses the enter key.
+///
+/// All keyboard input received in a frame is applied in order, so the event contains the value at the time that the
+/// key was pressed, and anything typed afterwards in the same frame is applied to the text input after submission.
+/// One event is sent for every press, even if there are several in the same frame.
+#[derive(Event, Debug, Clone)]
+pub struct TextInputSubmitEvent {
+    /// The text input that triggered the event.
+    pub entity: Entity,
+    /// The string contained in the text input at the time of the event.
+    pub value: String,
+    /// The cursor position at the time of the event.
+    pub cursor_pos: usize,
+    /// If true, the text input was cleared after submission.
+    ///
+    /// See [`TextInputSettings::retain_on_submit`].
+    pub cleared: bool,
+}
+
+/// An event that is fired once per text input, when its text has been laid out for the first time.
+///
+/// Until then, measurements of the text input's inner text are not yet valid.
+#[derive(Event, Debug, Clone)]
+pub struct TextInputReadyEvent {
+    /// The text input that is ready.
+    pub entity: Entity,
+}
+
+/// An event that is fired when the user tries to delete the previous character of an empty text input.
+#[derive(Event, Debug, Clone)]
+pub struct TextInputEmptyBackspaceEvent {
+    /// The text input that triggered the event.
+    pub entity: Entity,
+}
+
+/// An event that is fired when the user cancels editing a text input by pressing escape.
+///
+/// See [`TextInputAction::Cancel`].
+#[derive(Event, Debug, Clone)]
+pub struct TextInputCancelEvent {
+    /// The text input that triggered the event.
+    pub entity: Entity,
+}
+
+/// An event that is fired when typed or inserted text is rejected.
+///
+/// Text is rejected when a [`TextInputNumeric`] text input doesn't accept some of its characters, or when it would
+/// exceed [`TextInputSettings::max_length`], even if some of it could be inserted. Pressing enter while a
+/// [`TextInputNumericInvalid::Reject`] text input has an invalid value is also rejected. At most one event is sent
+/// per text input each frame.
+#[derive(Event, Debug, Clone)]
+pub struct TextInputRejectEvent {
+    /// The text input that rejected the text.
+    pub entity: Entity,
+}
+
+/// An event that is fired when the value of a text input changes, whether by typing or by modifying its
+/// [`TextInputValue`].
+///
+/// This is not fired for the initial value. With a [`TextInputChangeDebounce`], consecutive changes are coalesced
+/// into a single event.
+#[derive(Event, Debug, Clone)]
+pub struct TextInputChangedEvent {
+    /// The text input whose value changed.
+    pub entity: Entity,
+    /// The new value.
+    pub value: String,
+}
+
+/// A component that delays a text input's [`TextInputChangedEvent`] until its value has stopped changing for the
+/// given duration.
+///
+/// Each change restarts the delay, and a single event with the latest value is sent once it elapses. This is useful
+/// for e.g. searching as the user types without sending a request for every keystroke.
+///
+/// This is not added to text inputs by default. Without it, the event is sent in the same frame as the change.
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+pub struct TextInputChangeDebounce(pub Duration);
+
+/// An event that is fired for every keystroke that a text input with [`TextInputKeyEvents`] handles.
+///
+/// This is also sent when the keystroke has no effect, e.g. when moving the cursor left while it is already at the
+/// start of the value.
+#[derive(Event, Debug, Clone)]
+pub struct TextInputKeyEvent {
+    /// The text input that handled the keystroke.
+    pub entity: Entity,
+    /// The action performed. Typed text is reported as [`TextInputAction::InsertText`].
+    pub action: TextInputAction,
+}
+
+/// A marker component that enables [`TextInputKeyEvent`]s for a text input.
+///
+/// This is not added to text inputs by default, so that apps that don't need an event for every keystroke don't pay
+/// for them.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+pub struct TextInputKeyEvents;
+
+/// A `SystemParam` for reading the values of all text inputs at once.
+///
+/// The text inputs can optionally be narrowed down with a query filter, e.g. `TextInputs<With<MyForm>>`.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_simple_text_input::TextInputs;
+/// #[derive(Component, Clone, PartialEq, Eq, Hash)]
+/// struct FieldName(&'static str);
+///
+/// fn save_form(text_inputs: TextInputs, names: Query<&FieldName>) {
+///     let values = text_inputs.collect_by(&names);
+///     info!("{:?}", values.get(&FieldName("email")));
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct TextInputs<'w, 's, F: QueryFilter + 'static = ()> {
+    query: Query<'w, 's, (Entity, &'static TextInputValue), F>,
+}
+
+impl<F: QueryFilter> TextInputs<'_, '_, F> {
+    /// Returns an iterator over every text input and its value.
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, &str)> {
+        self.query
+            .iter()
+            .map(|(entity, value)| (entity, value.0.as_str()))
+    }
+
+    /// Returns the value of the given text input, if it exists.
+    pub fn get(&self, entity: Entity) -> Option<&str> {
+        self.query
+            .get(entity)
+            .ok()
+            .map(|(_, value)| value.0.as_str())
+    }
+
+    /// Collects the values of all text inputs that have a `K` component into a map keyed by that component.
+    pub fn collect_by<K: Component + Clone + Eq + Hash>(
+        &self,
+        keys: &Query<&K>,
+    ) -> HashMap<K, String> {
+        self.iter()
+            .filter_map(|(entity, value)| {
+                keys.get(entity)
+                    .ok()
+                    .map(|key| (key.clone(), value.to_string()))
+            })
+            .collect()
+    }
+}
+
+/// A `SystemParam` for inspecting the layout of text inputs, e.g. to draw custom overlays.
+#[derive(SystemParam)]
+pub struct TextInputLayout<'w, 's> {
+    children_query: Query<'w, 's, &'static Children>,
+    inner_query: Query<
+        'w,
+        's,
+        (
+            &'static TextLayoutInfo,
+            &'static ComputedNode,
+            &'static GlobalTransform,
+            &'static Parent,
+        ),
+        With<TextInputInner>,
+    >,
+    node_query: Query<'w, 's, (&'static ComputedNode, &'static GlobalTransform)>,
+}
+
+impl TextInputLayout<'_, '_> {
+    /// Returns the `TextLayoutInfo` of the given text input's inner text, e.g. to read the positions of its glyphs.
+    ///
+    /// The inner text contains a span for the text before the cursor, the cursor itself and the text after the
+    /// cursor, with span indices 0, 1 and 2. Glyph positions are the centers of the glyphs in physical pixels,
+    /// relative to the inner text. Returns `None` if the text input has not been laid out yet.
+    pub fn text_layout(&self, entity: Entity) -> Option<&TextLayoutInfo> {
+        let (layout, _, _, _) = self.inner_query.get(self.inner_entity(entity)?).ok()?;
+
+        Some(layout)
+    }
+
+    /// Returns the width of the given text input's rendered text in logical pixels, including the cursor.
+    ///
+    /// This may be wider than the text input itself if the text is scrolled. Returns `None` if the text input
+    /// has not been laid out yet.
+    pub fn text_width(&self, entity: Entity) -> Option<f32> {
+        let (_, node, _, _) = self.inner_query.get(self.inner_entity(entity)?).ok()?;
+
+        Some(node.size().x * node.inverse_scale_factor())
+    }
+
+    /// Returns the horizontal position of the character at `char_index` in the given text input, in logical
+    /// pixels relative to the left edge of its visible text.
+    ///
+    /// The current scroll position is taken into account, so characters that are scrolled out of view are
+    /// at negative positions or beyond the width of the text input. An index equal to the length of the value
+    /// gives the position after the last character.
+    ///
+    /// The index refers to the displayed text, which may differ from [`TextInputValue`] when a
+    /// [`TextInputMaskFn`] is used. Returns `None` if the index is out of bounds or the text input has not
+    /// been laid out yet.
+    pub fn glyph_x(&self, entity: Entity, char_index: usize) -> Option<f32> {
+        let (layout, node, transform, parent) =
+            self.inner_query.get(self.inner_entity(entity)?).ok()?;
+        let (overflow_node, overflow_transform) = self.node_query.get(parent.get()).ok()?;
+
+        // The cursor is not part of the value, so skip its glyph.
+        let glyphs: Vec<_> = layout
+            .glyphs
+            .iter()
+            .filter(|glyph| glyph.span_index != 1)
+            .collect();
+
+        // glyph positions are the centers of the glyphs in physical pixels
+        let x = match glyphs.get(char_index) {
+            Some(glyph) => glyph.position.x - glyph.size.x / 2.0,
+            None if char_index == glyphs.len() => layout
+                .glyphs
+                .last()
+                .map_or(0.0, |glyph| glyph.position.x + glyph.size.x / 2.0),
+            None => return None,
         };
 
-        // glyph positions are not adjusted for scale factor so we do that here
-        let window_ref = match target_camera {
-            Some(target) => {
-                let Ok(camera) = camera_query.get(target.0) else {
+        let left = transform.translation().x - node.size().x / 2.0;
+        let overflow_left = overflow_transform.translation().x - overflow_node.size().x / 2.0;
+
+        Some((left - overflow_left + x) * node.inverse_scale_factor())
+    }
+
+    fn inner_entity(&self, entity: Entity) -> Option<Entity> {
+        self.children_query
+            .iter_descendants(entity)
+            .find(|descendant| self.inner_query.contains(*descendant))
+    }
+}
+
+/// A convenience parameter for dealing with a text input's inner Bevy `Text` entity.
+#[derive(SystemParam)]
+struct InnerText<'w, 's> {
+    text_query: Query<'w, 's, (), With<TextInputInner>>,
+    children_query: Query<'w, 's, &'static Children>,
+    text_input_query: Query<'w, 's, (), With<TextInput>>,
+}
+impl InnerText<'_, '_> {
+    fn inner_entity(&self, entity: Entity) -> Option<Entity> {
+        let inner = self.find_inner_entity(entity);
+
+        // Entities without the `TextInput` marker are rendered by the user and have no inner entity.
+        if inner.is_none() && self.text_input_query.contains(entity) {
+            debug!("Text input {entity} has no inner text entity. Its children may have been despawned.");
+        }
+
+        inner
+    }
+
+    // Like `inner_entity`, but for when it is expected that there may not be an inner entity yet.
+    fn find_inner_entity(&self, entity: Entity) -> Option<Entity> {
+        self.children_query
+            .iter_descendants(entity)
+            .find(|descendant_entity| self.text_query.get(*descendant_entity).is_ok())
+    }
+}
+
+fn keyboard(
+    mut key_input: ResMut<ButtonInput<KeyCode>>,
+    input_events: Res<Events<KeyboardInput>>,
+    mut input_reader: ResMut<KeyboardInputCursor>,
+    mut pending_dead_key: Local<Option<char>>,
+    // The position of the last letter that was capitalized automatically, and whether it was removed with backspace.
+    mut auto_capitalized: Local<Option<(Entity, usize, bool)>>,
+    mut text_input_query: Query<(
+        Entity,
+        &TextInputSettings,
+        &mut TextInputInactive,
+        &mut TextInputValue,
+        &mut TextInputCursorPos,
+        &mut TextInputCursorTimer,
+        Option<&mut TextInputCommitted>,
+        Option<&mut TextInputSubmitHistory>,
+        Option<&mut TextInputKillRing>,
+        Option<&mut TextInputDirty>,
+        Option<&mut TextInputRejectFlash>,
+        Option<&TextInputNumeric>,
+        Has<TextInputKeyEvents>,
+    )>,
+    mut submit_writer: EventWriter<TextInputSubmitEvent>,
+    mut empty_backspace_writer: EventWriter<TextInputEmptyBackspaceEvent>,
+    mut key_writer: EventWriter<TextInputKeyEvent>,
+    mut cancel_writer: EventWriter<TextInputCancelEvent>,
+    mut reject_writer: EventWriter<TextInputRejectEvent>,
+    navigation: Res<TextInputNavigationBindings>,
+    #[cfg(feature = "recording")] mut replay_query: Query<&mut TextInputReplay>,
+) {
+    // Finished replays have nothing left to apply, so they must not keep this running without keyboard input.
+    #[cfg(feature = "recording")]
+    let replaying = replay_query.iter().any(|replay| !replay.is_finished());
+    #[cfg(not(feature = "recording"))]
+    let replaying = false;
+
+    if !replaying && input_reader.0.clone().read(&input_events).next().is_none() {
+        return;
+    }
+
+    // collect actions that have all required modifiers held
+    let valid_actions = navigation
+        .0
+        .iter()
+        .filter(|(_, TextInputBinding { modifiers, .. })| {
+            modifiers.iter().all(|m| key_input.pressed(*m))
+        })
+        .map(|(action, TextInputBinding { key, trigger, .. })| (key, trigger, action));
+
+    let mut next_dead_key = None;
+    let mut consumed = Vec::new();
+
+    for (
+        input_entity,
+        settings,
+        mut inactive,
+        mut text_input,
+        mut cursor_pos,
+        mut cursor_timer,
+        mut committed,
+        mut history,
+        mut kill_ring,
+        mut dirty,
+        mut reject_flash,
+        numeric,
+        key_events,
+    ) in &mut text_input_query
+    {
+        #[cfg(feature = "recording")]
+        let replayed = replay_query
+            .get_mut(input_entity)
+            .map(|mut replay| replay.take_due())
+            .unwrap_or_default();
+        #[cfg(not(feature = "recording"))]
+        let replayed: Vec<TextInputAction> = Vec::new();
+
+        if inactive.0 && replayed.is_empty() {
+            continue;
+        }
+
+        let mut rejected = false;
+
+        // History bindings only apply to text inputs with a history, so that e.g. `ArrowUp` isn't consumed otherwise.
+        let has_history = history.is_some();
+
+        // An inactive text input that is only replaying actions must not affect the pending dead key.
+        let reads_keys = !inactive.0;
+        let mut dead_key = *pending_dead_key;
+
+        // Replayed actions are applied before this frame's keystrokes.
+        let mut reader = input_reader.0.clone();
+        let steps = replayed
+            .into_iter()
+            .map(KeyboardStep::Replay)
+            .chain(reader.read(&input_events).map(KeyboardStep::Key));
+
+        for step in steps {
+            let (input, action) = match step {
+                KeyboardStep::Key(input) => {
+                    // Keystrokes that follow a submit or cancel that blurred the text input are ignored.
+                    if inactive.0 {
+                        continue;
+                    }
+
+                    let mut bindings = valid_actions
+                        .clone()
+                        .filter(|(key, _, action)| {
+                            key.matches(input)
+                                && (has_history
+                                    || !matches!(
+                                        action,
+                                        TextInputAction::HistoryPrev | TextInputAction::HistoryNext
+                                    ))
+                        })
+                        .peekable();
+                    let bound = bindings.peek().is_some();
+                    let action = bindings
+                        .find(|(_, trigger, _)| trigger.matches(input))
+                        .map(|(_, _, action)| Cow::Borrowed(action));
+
+                    // Releasing a key only does something if it is bound to an action that is triggered on release,
+                    // and pressing such a key must not insert it as text.
+                    if action.is_none() && (bound || !input.state.is_pressed()) {
+                        if bound && settings.consume_input && input.state.is_pressed() {
+                            consumed.push(input.key_code);
+                        }
+                        continue;
+                    }
+
+                    (Some(input), action)
+                }
+                KeyboardStep::Replay(action) => (None, Some(Cow::Owned(action))),
+            };
+
+            // The cursor may have been set out of range since `update_value` last clamped it.
+            let len = text_input.0.chars().count();
+            let pos = cursor_pos.bypass_change_detection().0.min(len);
+
+            let value_before = dirty.is_some().then(|| text_input.0.clone());
+
+            if let Some(input) = input.filter(|_| settings.consume_input) {
+                // Modifier keys and other keys that are not handled must remain pressed.
+                let handled = action.is_some()
+                    || matches!(
+                        input.logical_key,
+                        Key::Dead(Some(_)) | Key::Space | Key::Character(_)
+                    );
+                if handled {
+                    consumed.push(input.key_code);
+                }
+            }
+
+            if let Some(action) = action {
+                // Holding the submit key must not submit repeatedly, e.g. sending a chat message twice.
+                if input.is_some_and(|input| input.repeat)
+                    && matches!(*action, TextInputAction::Submit)
+                {
                     continue;
-                };
+                }
+
+                if key_events {
+                    key_writer.send(TextInputKeyEvent {
+                        entity: input_entity,
+                        action: action.clone().into_owned(),
+                    });
+                }
+
+                use TextInputAction::*;
+                let mut timer_should_reset = true;
+                match action.as_ref() {
+                    CharLeft => {
+                        if pos > 0 {
+                            cursor_pos.0 = grapheme_at(&text_input.0, pos - 1).start;
+                        }
+                    }
+                    CharRight => {
+                        if pos < len {
+                            cursor_pos.0 = grapheme_at(&text_input.0, pos).end;
+                        }
+                    }
+                    LineStart => cursor_pos.0 = 0,
+                    LineEnd => cursor_pos.0 = len,
+                    WordLeft => cursor_pos.0 = word_left(&text_input.0, pos, settings.word_stop),
+                    WordRight => {
+                        cursor_pos.0 = word_right(
+                            &text_input.0,
+                            pos,
+                            settings.word_stop,
+                            settings.word_right_stop,
+                        )
+                    }
+                    DeletePrev => {
+                        if pos > 0 {
+                            let grapheme = grapheme_at(&text_input.0, pos - 1);
+                            cursor_pos.0 = grapheme.start;
+                            remove_chars(&mut text_input.0, grapheme);
+
+                            if let Some((entity, capitalized_pos, removed)) =
+                                auto_capitalized.as_mut()
+                            {
+                                *removed =
+                                    *entity == input_entity && *capitalized_pos == cursor_pos.0;
+                            }
+                        } else if text_input.0.is_empty() {
+                            empty_backspace_writer.send(TextInputEmptyBackspaceEvent {
+                                entity: input_entity,
+                            });
+                        }
+                    }
+                    DeleteNext => {
+                        if pos < len {
+                            let grapheme = grapheme_at(&text_input.0, pos);
+
+                            // Ensure that the cursor isn't reset
+                            cursor_pos.0 = grapheme.start;
+                            remove_chars(&mut text_input.0, grapheme);
+                        }
+                    }
+                    InsertText(text) => {
+                        let (inserted, was_rejected) =
+                            insert_str_at(&mut text_input.0, pos, text, settings, numeric);
+                        cursor_pos.0 = pos + inserted;
+                        rejected |= was_rejected;
+                    }
+                    Submit => {
+                        if settings.ignore_empty_submit && text_input.0.trim().is_empty() {
+                            continue;
+                        }
+
+                        let invalid = numeric.is_some_and(|numeric| {
+                            numeric.on_invalid == TextInputNumericInvalid::Reject
+                                && TextInputNumeric::parse(&text_input.0).is_none()
+                        });
+                        if invalid {
+                            rejected = true;
+                            continue;
+                        }
+
+                        if let Some(committed) = committed.as_mut() {
+                            committed.commit(&text_input);
+                        }
+                        if let Some(history) = history.as_mut() {
+                            history.push(text_input.0.as_str());
+                        }
+
+                        let (value, cleared) = if settings.retain_on_submit {
+                            (text_input.0.clone(), false)
+                        } else {
+                            cursor_pos.0 = 0;
+                            (std::mem::take(&mut text_input.0), true)
+                        };
+                        submit_writer.send(TextInputSubmitEvent {
+                            entity: input_entity,
+                            value,
+                            cursor_pos: pos,
+                            cleared,
+                        });
+                        timer_should_reset = false;
+
+                        if let Some(dirty) = dirty.as_mut() {
+                            dirty.set_if_neq(TextInputDirty(false));
+                        }
+
+                        if settings.blur_on_submit {
+                            inactive.0 = true;
+                            continue;
+                        }
+                    }
+                    Commit => {
+                        if let Some(committed) = committed.as_mut() {
+                            committed.commit(&text_input);
+                        }
+                        if let Some(dirty) = dirty.as_mut() {
+                            dirty.set_if_neq(TextInputDirty(false));
+                        }
+                        timer_should_reset = false;
+                    }
+                    Revert => {
+                        if let Some(committed) = committed.as_ref() {
+                            committed.revert(&mut text_input);
+                            cursor_pos.0 = text_input.0.chars().count();
+
+                            if let Some(dirty) = dirty.as_mut() {
+                                dirty.set_if_neq(TextInputDirty(false));
+                            }
+                        }
+                    }
+                    Cancel => {
+                        if let Some(committed) = committed.as_ref() {
+                            committed.revert(&mut text_input);
+                            cursor_pos.0 = text_input.0.chars().count();
+
+                            if let Some(dirty) = dirty.as_mut() {
+                                dirty.set_if_neq(TextInputDirty(false));
+                            }
+                        }
+                        cancel_writer.send(TextInputCancelEvent {
+                            entity: input_entity,
+                        });
+
+                        if settings.blur_on_cancel {
+                            inactive.0 = true;
+                            continue;
+                        }
+                    }
+                    HistoryPrev => {
+                        if let Some(entry) =
+                            history.as_mut().and_then(|h| h.recall_prev(&text_input.0))
+                        {
+                            text_input.0 = entry.to_string();
+                            cursor_pos.0 = text_input.0.chars().count();
+                        }
+                    }
+                    HistoryNext => {
+                        if let Some(entry) = history.as_mut().and_then(|h| h.recall_next()) {
+                            text_input.0 = entry.to_string();
+                            cursor_pos.0 = text_input.0.chars().count();
+                        }
+                    }
+                    Transpose => {
+                        if let Some(pos) = transpose_chars(&mut text_input.0, pos) {
+                            cursor_pos.0 = pos;
+                        }
+                    }
+                    KillToLineEnd => {
+                        if pos < len {
+                            let byte_pos = byte_pos(&text_input.0, pos);
+                            let killed = text_input.0.split_off(byte_pos);
+                            if let Some(kill_ring) = kill_ring.as_mut() {
+                                kill_ring.push(killed);
+                            }
+
+                            // Ensure that the cursor isn't reset
+                            cursor_pos.0 = pos;
+                        }
+                    }
+                    KillToLineStart => {
+                        if pos > 0 {
+                            let byte_pos = byte_pos(&text_input.0, pos);
+                            let killed: String = text_input.0.drain(..byte_pos).collect();
+                            if let Some(kill_ring) = kill_ring.as_mut() {
+                                kill_ring.push(killed);
+                            }
+                            cursor_pos.0 = 0;
+                        }
+                    }
+                    Yank => {
+                        if let Some(text) = kill_ring.as_ref().and_then(|k| k.last()) {
+                            let (inserted, was_rejected) =
+                                insert_str_at(&mut text_input.0, pos, text, settings, numeric);
+                            cursor_pos.0 = pos + inserted;
+                            rejected |= was_rejected;
+                        }
+                    }
+                }
+
+                if !matches!(*action, Submit | Commit | Revert | Cancel) {
+                    mark_dirty(dirty.as_mut(), value_before.as_deref(), &text_input.0);
+                }
+
+                let is_navigation = matches!(
+                    *action,
+                    CharLeft | CharRight | LineStart | LineEnd | WordLeft | WordRight
+                );
+                if is_navigation && settings.keep_blinking_on_navigation {
+                    timer_should_reset = false;
+                }
+
+                cursor_timer.should_reset |= timer_should_reset;
+
+                // Keys that are bound to an action are never also inserted as text.
+                continue;
+            }
+
+            let Some(input) = input else {
+                continue;
+            };
+
+            let text = match input.logical_key {
+                // Dead keys are held until the next character, which they are combined with
+                // if possible.
+                Key::Dead(Some(c)) => {
+                    dead_key = Some(c);
+                    continue;
+                }
+                // A dead key followed by a space produces the dead key's character by itself.
+                Key::Space => match dead_key.take() {
+                    Some(dead) => dead.to_string(),
+                    None if settings.trim_leading_spaces && pos == 0 => continue,
+                    None if settings.collapse_spaces
+                        && is_whitespace_around(&text_input.0, pos) =>
+                    {
+                        continue
+                    }
+                    None => {
+                        let shift =
+                            key_input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+                        settings
+                            .shift_space
+                            .filter(|_| shift)
+                            .unwrap_or(' ')
+                            .to_string()
+                    }
+                },
+                Key::Character(ref s) => match dead_key.take() {
+                    Some(dead) => compose_dead_key(dead, s),
+                    None => s.to_string(),
+                },
+                _ => continue,
+            };
+
+            // Leave a letter as typed if the user just removed its automatically capitalized version.
+            let text = match auto_capitalized.take() {
+                Some((entity, capitalized_pos, true))
+                    if entity == input_entity && capitalized_pos == pos =>
+                {
+                    text
+                }
+                _ => {
+                    let before = &text_input.0[..byte_pos(&text_input.0, pos)];
+                    match settings.auto_capitalize.apply(before, &text) {
+                        Some(capitalized) => {
+                            *auto_capitalized = Some((input_entity, pos, false));
+                            capitalized
+                        }
+                        None => text,
+                    }
+                }
+            };
+
+            if key_events {
+                key_writer.send(TextInputKeyEvent {
+                    entity: input_entity,
+                    action: TextInputAction::InsertText(text.clone()),
+                });
+            }
+
+            let (inserted, was_rejected) =
+                insert_str_at(&mut text_input.0, pos, &text, settings, numeric);
+            cursor_pos.0 = pos + inserted;
+            rejected |= was_rejected;
+
+            mark_dirty(dirty.as_mut(), value_before.as_deref(), &text_input.0);
+
+            cursor_timer.should_reset = true;
+        }
+
+        if reads_keys {
+            next_dead_key = dead_key;
+        }
+
+        if rejected {
+            if let Some(flash) = reject_flash.as_mut() {
+                flash.start();
+            }
+            reject_writer.send(TextInputRejectEvent {
+                entity: input_entity,
+            });
+        }
+    }
+
+    for key_code in consumed {
+        key_input.reset(key_code);
+    }
+
+    *pending_dead_key = next_dead_key;
+    input_reader.0.clear(&input_events);
+}
+
+// Discards keyboard input while the plugin is disabled, so that it isn't typed once it is enabled again.
+fn skip_keyboard_input(
+    input_events: Res<Events<KeyboardInput>>,
+    mut input_reader: ResMut<KeyboardInputCursor>,
+) {
+    input_reader.0.clear(&input_events);
+}
+
+#[cfg(feature = "recording")]
+fn advance_replays(mut replay_query: Query<&mut TextInputReplay>, time: Res<Time>) {
+    for mut replay in &mut replay_query {
+        if !replay.is_finished() {
+            replay.elapsed += time.delta();
+        }
+    }
+}
+
+#[cfg(feature = "recording")]
+fn record(
+    mut recording_query: Query<&mut TextInputRecording>,
+    mut key_reader: EventReader<TextInputKeyEvent>,
+    time: Res<Time>,
+) {
+    for mut recording in &mut recording_query {
+        recording.elapsed += time.delta();
+    }
+
+    for event in key_reader.read() {
+        let Ok(mut recording) = recording_query.get_mut(event.entity) else {
+            continue;
+        };
+
+        let time = recording.elapsed;
+        recording.entries.push(TextInputRecordedAction {
+            time,
+            action: event.action.clone(),
+        });
+    }
+}
+
+// A keystroke, or an action replayed by `TextInputReplay`.
+enum KeyboardStep<'a> {
+    Key(&'a KeyboardInput),
+    Replay(TextInputAction),
+}
+
+// Marks a text input as dirty if the user changed its value.
+fn mark_dirty(dirty: Option<&mut Mut<TextInputDirty>>, value_before: Option<&str>, value: &str) {
+    if let (Some(dirty), Some(value_before)) = (dirty, value_before) {
+        if value_before != value {
+            dirty.set_if_neq(TextInputDirty(true));
+        }
+    }
+}
+
+fn insert_dropped_files(
+    mut events: EventReader<FileDragAndDrop>,
+    mut input_query: Query<(
+        Entity,
+        &TextInputSettings,
+        &TextInputInactive,
+        &mut TextInputValue,
+        &mut TextInputCursorPos,
+        Option<&mut TextInputDirty>,
+        Option<&mut TextInputRejectFlash>,
+        Option<&TextInputNumeric>,
+    )>,
+    mut reject_writer: EventWriter<TextInputRejectEvent>,
+) {
+    for event in events.read() {
+        let FileDragAndDrop::DroppedFile { path_buf, .. } = event else {
+            continue;
+        };
+
+        let path = path_buf.to_string_lossy();
+
+        for (
+            entity,
+            settings,
+            inactive,
+            mut text_input,
+            mut cursor_pos,
+            mut dirty,
+            mut reject_flash,
+            numeric,
+        ) in &mut input_query
+        {
+            if inactive.0 || !settings.insert_dropped_files {
+                continue;
+            }
+
+            let pos = cursor_pos.0.min(text_input.0.chars().count());
+            let (inserted, rejected) =
+                insert_str_at(&mut text_input.0, pos, &path, settings, numeric);
+            cursor_pos.0 = pos + inserted;
+
+            if rejected {
+                if let Some(flash) = reject_flash.as_mut() {
+                    flash.start();
+                }
+                reject_writer.send(TextInputRejectEvent { entity });
+            }
+
+            if let Some(dirty) = dirty.as_mut().filter(|_| inserted > 0) {
+                dirty.set_if_neq(TextInputDirty(true));
+            }
+        }
+    }
+}
+
+fn clear_on_focus(
+    mut input_query: Query<(
+        Entity,
+        &TextInputSettings,
+        Ref<TextInputInactive>,
+        &mut TextInputValue,
+    )>,
+    mut cleared: Local<HashMap<Entity, String>>,
+    // Whether each text input was inactive when this last saw it change, so that writing the same value again is not
+    // mistaken for focusing or blurring it.
+    mut was_inactive: Local<HashMap<Entity, bool>>,
+) {
+    for (entity, settings, inactive, mut text_input) in &mut input_query {
+        if !settings.clear_on_focus || !inactive.is_changed() {
+            continue;
+        }
+
+        let before = was_inactive.insert(entity, inactive.0);
+        if inactive.is_added() || before.is_none_or(|before| before == inactive.0) {
+            continue;
+        }
+
+        if !inactive.0 {
+            if !text_input.0.is_empty() {
+                cleared.insert(entity, std::mem::take(&mut text_input.0));
+            }
+        } else if let Some(value) = cleared.remove(&entity) {
+            if text_input.0.is_empty() {
+                text_input.0 = value;
+            }
+        }
+    }
+
+    // Forget the values of text inputs that were despawned while active.
+    if !cleared.is_empty() {
+        cleared.retain(|entity, _| input_query.contains(*entity));
+    }
+    if was_inactive.len() > input_query.iter().len() {
+        was_inactive.retain(|entity, _| input_query.contains(*entity));
+    }
+}
+
+fn edit_in_place(
+    mut input_query: Query<(
+        Entity,
+        Ref<TextInputEditInPlace>,
+        &mut TextInputInactive,
+        &TextInputValue,
+        &TextInputCursorPos,
+    )>,
+    mut submit_events: ResMut<Events<TextInputSubmitEvent>>,
+    mut submit_reader: Local<EventCursor<TextInputSubmitEvent>>,
+    // Whether each text input was inactive when this last ran, so that only blurring it submits its value.
+    mut was_inactive: Local<HashMap<Entity, bool>>,
+) {
+    // Text inputs that were blurred by pressing enter have already been submitted.
+    let submitted: Vec<Entity> = submit_reader
+        .read(&submit_events)
+        .map(|event| event.entity)
+        .collect();
+
+    for (entity, edit_in_place, mut inactive, text_input, cursor_pos) in &mut input_query {
+        if edit_in_place.is_added() {
+            if inactive.0 {
+                inactive.0 = false;
+            }
+            was_inactive.insert(entity, false);
+            continue;
+        }
+
+        let blurred = was_inactive.insert(entity, inactive.0) == Some(false) && inactive.0;
+        if blurred && !submitted.contains(&entity) {
+            submit_events.send(TextInputSubmitEvent {
+                entity,
+                value: text_input.0.clone(),
+                cursor_pos: cursor_pos.0,
+                cleared: false,
+            });
+        }
+    }
+
+    // Forget text inputs that were despawned or are no longer edited in place.
+    if was_inactive.len() > input_query.iter().len() {
+        was_inactive.retain(|entity, _| input_query.contains(*entity));
+    }
+}
+
+fn parse_numeric(
+    mut events: EventReader<TextInputSubmitEvent>,
+    mut input_query: Query<(
+        &TextInputNumeric,
+        &mut TextInputNumericValue,
+        &mut TextInputValue,
+    )>,
+) {
+    for event in events.read() {
+        let Ok((numeric, mut number, mut text_input)) = input_query.get_mut(event.entity) else {
+            continue;
+        };
+
+        let parsed = TextInputNumeric::parse(&event.value);
+        let clamped = match parsed {
+            Some(n) => Some(numeric.clamp(n)),
+            None if numeric.on_invalid == TextInputNumericInvalid::Clear => None,
+            None => number.0,
+        };
+
+        if !event.cleared && parsed != clamped {
+            if let Some(clamped) = clamped {
+                text_input.0 = clamped.to_string();
+            }
+        }
+
+        number.0 = clamped;
+    }
+}
+
+fn update_value(
+    mut input_query: Query<
+        (
+            Entity,
+            Mut<TextInputValue>,
+            &TextInputSettings,
+            &TextInputInactive,
+            &mut TextInputCursorPos,
+            Option<&TextInputMaskFn>,
+        ),
+        Or<(
+            Changed<TextInputValue>,
+            Changed<TextInputCursorPos>,
+            Changed<TextInputInactive>,
+            Changed<TextInputMaskFn>,
+        )>,
+    >,
+    inner_text: InnerText,
+    mut writer: TextUiWriter,
+    mut inner_scroll_query: Query<(&mut Node, &mut TextInputInnerScroll), With<TextInputInner>>,
+) {
+    for (entity, mut text_input, settings, inactive, mut cursor_pos, mask_fn) in &mut input_query {
+        let Some(inner) = inner_text.inner_entity(entity) else {
+            continue;
+        };
+
+        if let Some(max_length) = settings.max_length {
+            if text_input.is_changed()
+                && !settings.keep_overlong_value
+                && text_input.0.chars().count() > max_length
+            {
+                let byte_pos = byte_pos(&text_input.0, max_length);
+                text_input.0.truncate(byte_pos);
+            }
+        }
+
+        // Reset the cursor to the end of the input when the value is changed by
+        // a user manipulating the value component. If the cursor was changed too,
+        // keep it, but make sure it is still within the value.
+        let len = text_input.0.chars().count();
+        let reset = text_input.is_changed() && !cursor_pos.is_changed();
+        if reset || cursor_pos.0 > len {
+            cursor_pos.0 = len;
+        }
+
+        // A cursor in the middle of a grapheme cluster is moved to its start.
+        let grapheme = grapheme_at(&text_input.0, cursor_pos.0);
+        if grapheme.start < cursor_pos.0 {
+            cursor_pos.0 = grapheme.start;
+        }
+
+        let (value, display_cursor_pos, mask) =
+            display_value(&text_input.0, cursor_pos.0, settings, inactive, mask_fn);
+        let values = get_section_values(&value, display_cursor_pos);
+
+        if let Ok((mut style, mut scroll)) = inner_scroll_query.get_mut(inner) {
+            let cursor_at_end = values.2.is_empty();
+            if scroll.cursor_at_end != cursor_at_end {
+                scroll.cursor_at_end = cursor_at_end;
+            }
+
+            // Reset the scroll position of an emptied input right away, rather than waiting for
+            // `scroll_with_cursor` to see the new layout.
+            if text_input.0.is_empty() {
+                if style.left != Val::Auto {
+                    style.left = Val::Auto;
+                }
+                scroll.target = None;
+            }
+        }
+
+        // Only touch sections whose contents actually changed, reusing their existing buffers.
+        set_section_value(writer.text(inner, 0), values.0, mask);
+        set_section_value(writer.text(inner, 1), values.1, None);
+        set_section_value(writer.text(inner, 2), values.2, mask);
+    }
+}
+
+fn scroll_with_cursor(
+    mut inner_text_query: Query<
+        (
+            &TextLayoutInfo,
+            &mut Node,
+            &ComputedNode,
+            &Parent,
+            Option<&TargetCamera>,
+            &mut TextInputInnerScroll,
+        ),
+        (With<TextInputInner>, Changed<TextLayoutInfo>),
+    >,
+    mut style_query: Query<(&ComputedNode, &mut Node), Without<TextInputInner>>,
+    parent_query: Query<&Parent>,
+    text_input_query: Query<(), With<TextInput>>,
+    settings_query: Query<&TextInputSettings, Without<TextInputNoScroll>>,
+    camera_query: Query<&Camera>,
+    window_query: Query<&Window>,
+    primary_window_query: Query<&Window, With<PrimaryWindow>>,
+) {
+    for (layout, mut style, child_node, parent, target_camera, mut scroll) in
+        inner_text_query.iter_mut()
+    {
+        // Text inputs with a custom inner entity don't scroll, because there is no overflow container to scroll in.
+        let Some(text_input) = text_input_of(parent, &parent_query, &text_input_query)
+            .filter(|text_input| *text_input != parent.get())
+        else {
+            continue;
+        };
+
+        // This also skips text inputs with `TextInputNoScroll`.
+        let Ok(settings) = settings_query.get(text_input) else {
+            continue;
+        };
+
+        let Ok((parent_node, mut parent_style)) = style_query.get_mut(parent.get()) else {
+            continue;
+        };
+
+        if settings.disable_scroll {
+            continue;
+        }
+
+        let child_size = child_node.size().x;
+        let parent_size = parent_node.size().x;
+
+        // if the text fits, there is nothing to scroll, so leave the layout alone
+        if child_size <= parent_size {
+            if style.left != Val::Auto {
+                style.left = Val::Auto;
+            }
+            scroll.target = None;
+            continue;
+        }
+
+        // no text -> do nothing
+        if layout.glyphs.is_empty() {
+            continue;
+        }
+
+        // if cursor is at the end, position at FlexEnd so newly typed text does not take a frame to move into view
+        if scroll.cursor_at_end {
+            style.left = Val::Auto;
+            parent_style.justify_content = JustifyContent::FlexEnd;
+            scroll.target = None;
+            continue;
+        }
+
+        // if cursor is in the middle, we use FlexStart + `left` px for consistent behaviour when typing the middle
+
+        let Some(cursor_pos) = layout
+            .glyphs
+            .iter()
+            .find(|g| g.span_index == 1)
+            .map(|p| p.position.x)
+        else {
+            continue;
+        };
+
+        // glyph positions are not adjusted for scale factor so we do that here
+        let window_ref = match target_camera {
+            Some(target) => {
+                let Ok(camera) = camera_query.get(target.0) else {
+                    continue;
+                };
+
+                match camera.target {
+                    RenderTarget::Window(window_ref) => Some(window_ref),
+                    _ => None,
+                }
+            }
+            None => Some(WindowRef::Primary),
+        };
+
+        let scale_factor = match window_ref {
+            Some(window_ref) => {
+                let window = match window_ref {
+                    WindowRef::Entity(w) => window_query.get(w).ok(),
+                    WindowRef::Primary => primary_window_query.get_single().ok(),
+                };
+
+                let Some(window) = window else {
+                    continue;
+                };
+
+                window.scale_factor()
+            }
+            None => 1.0,
+        };
+        let cursor_pos = cursor_pos / scale_factor;
+
+        let box_pos = match scroll.target.map(Val::Px).unwrap_or(style.left) {
+            Val::Px(px) => -px,
+            _ => child_size - parent_size,
+        };
+
+        let relative_pos = cursor_pos - box_pos;
+
+        if relative_pos < 0.0 || relative_pos > parent_size {
+            let req_px = parent_size * 0.5 - cursor_pos;
+            let req_px = req_px.clamp(parent_size - child_size, 0.0);
+
+            match settings.scroll_smoothing {
+                Some(speed) => {
+                    // Start from the current position so that `smooth_scroll` can glide to the target.
+                    if style.left == Val::Auto {
+                        style.left = Val::Px(-box_pos);
+                    }
+                    scroll.target = Some(req_px);
+                    scroll.speed = speed;
+                }
+                None => style.left = Val::Px(req_px),
+            }
+            parent_style.justify_content = JustifyContent::FlexStart;
+        }
+    }
+}
+
+fn smooth_scroll(
+    mut inner_text_query: Query<(&mut Node, &mut TextInputInnerScroll), With<TextInputInner>>,
+    time: Res<Time>,
+) {
+    for (mut style, mut scroll) in &mut inner_text_query {
+        let Some(target) = scroll.target else {
+            continue;
+        };
+
+        let Val::Px(current) = style.left else {
+            scroll.target = None;
+            continue;
+        };
+
+        let next = current.lerp(target, 1.0 - (-scroll.speed * time.delta_secs()).exp());
+
+        if (next - target).abs() < 0.5 {
+            style.left = Val::Px(target);
+            scroll.target = None;
+        } else {
+            style.left = Val::Px(next);
+        }
+    }
+}
+
+// Triggers the events sent by the plugin for observers on the text inputs that they are about.
+fn trigger_observers(
+    mut commands: Commands,
+    mut submit_reader: EventReader<TextInputSubmitEvent>,
+    mut ready_reader: EventReader<TextInputReadyEvent>,
+    mut empty_backspace_reader: EventReader<TextInputEmptyBackspaceEvent>,
+    mut cancel_reader: EventReader<TextInputCancelEvent>,
+    mut reject_reader: EventReader<TextInputRejectEvent>,
+    mut key_reader: EventReader<TextInputKeyEvent>,
+    mut changed_reader: EventReader<TextInputChangedEvent>,
+) {
+    for event in submit_reader.read() {
+        commands.trigger_targets(event.clone(), event.entity);
+    }
+    for event in ready_reader.read() {
+        commands.trigger_targets(event.clone(), event.entity);
+    }
+    for event in empty_backspace_reader.read() {
+        commands.trigger_targets(event.clone(), event.entity);
+    }
+    for event in cancel_reader.read() {
+        commands.trigger_targets(event.clone(), event.entity);
+    }
+    for event in reject_reader.read() {
+        commands.trigger_targets(event.clone(), event.entity);
+    }
+    for event in key_reader.read() {
+        commands.trigger_targets(event.clone(), event.entity);
+    }
+    for event in changed_reader.read() {
+        commands.trigger_targets(event.clone(), event.entity);
+    }
+}
+
+fn send_changed(
+    input_query: Query<(
+        Entity,
+        Ref<TextInputValue>,
+        Option<&TextInputChangeDebounce>,
+    )>,
+    mut changed_writer: EventWriter<TextInputChangedEvent>,
+    // The time left before the debounced event of each text input is sent.
+    mut pending: Local<HashMap<Entity, Duration>>,
+    time: Res<Time>,
+) {
+    for (entity, text_input, debounce) in &input_query {
+        if text_input.is_changed() && !text_input.is_added() {
+            match debounce.filter(|debounce| !debounce.0.is_zero()) {
+                Some(debounce) => {
+                    pending.insert(entity, debounce.0);
+                }
+                None => {
+                    changed_writer.send(TextInputChangedEvent {
+                        entity,
+                        value: text_input.0.clone(),
+                    });
+                }
+            }
+            continue;
+        }
+
+        let Some(remaining) = pending.get_mut(&entity) else {
+            continue;
+        };
+
+        *remaining = remaining.saturating_sub(time.delta());
+        if remaining.is_zero() {
+            pending.remove(&entity);
+            changed_writer.send(TextInputChangedEvent {
+                entity,
+                value: text_input.0.clone(),
+            });
+        }
+    }
+
+    // Forget the pending events of text inputs that were despawned.
+    if !pending.is_empty() {
+        pending.retain(|entity, _| input_query.contains(*entity));
+    }
+}
+
+fn detect_ready(
+    mut commands: Commands,
+    inner_text_query: Query<
+        (Entity, &TextLayoutInfo, &Parent),
+        (
+            With<TextInputInner>,
+            Without<TextInputInnerReady>,
+            Changed<TextLayoutInfo>,
+        ),
+    >,
+    parent_query: Query<&Parent>,
+    text_input_query: Query<(), With<TextInput>>,
+    mut ready_writer: EventWriter<TextInputReadyEvent>,
+) {
+    for (inner, layout, parent) in &inner_text_query {
+        // The cursor is always present, so there are no glyphs until layout has happened.
+        if layout.glyphs.is_empty() {
+            continue;
+        }
+
+        let Some(text_input) = text_input_of(parent, &parent_query, &text_input_query) else {
+            continue;
+        };
+
+        commands.entity(inner).insert(TextInputInnerReady);
+        ready_writer.send(TextInputReadyEvent { entity: text_input });
+    }
+}
+
+// Finds the text input that an inner text entity belongs to. A custom inner entity is a child of the text input,
+// while the plugin's own is nested in an overflow container.
+fn text_input_of(
+    parent: &Parent,
+    parent_query: &Query<&Parent>,
+    text_input_query: &Query<(), With<TextInput>>,
+) -> Option<Entity> {
+    if text_input_query.contains(parent.get()) {
+        return Some(parent.get());
+    }
+
+    parent_query
+        .get(parent.get())
+        .ok()
+        .map(Parent::get)
+        .filter(|grandparent| text_input_query.contains(*grandparent))
+}
+
+fn create(
+    trigger: Trigger<OnAdd, TextInputValue>,
+    mut commands: Commands,
+    query: Query<(
+        Entity,
+        &TextInputTextFont,
+        &TextInputTextColor,
+        &TextInputValue,
+        Option<&TextInputCursorPos>,
+        &TextInputInactive,
+        &TextInputSettings,
+        &TextInputPlaceholder,
+        Option<&Name>,
+        Option<&TextInputMaskFn>,
+        &Node,
+        Option<&TargetCamera>,
+        Option<&TextInputShowCursor>,
+    )>,
+    cursor_font: Res<TextInputCursorFont>,
+    cursor_color: Res<TextInputCursorColor>,
+    inner_text: InnerText,
+    inner_scroll_query: Query<(), With<TextInputInnerScroll>>,
+) {
+    let custom_inner = inner_text.find_inner_entity(trigger.entity());
+
+    // If `TextInputValue` was removed and inserted again, the inner entities already exist.
+    if custom_inner.is_some_and(|inner| inner_scroll_query.contains(inner)) {
+        return;
+    }
+
+    if let Ok((
+        entity,
+        font,
+        color,
+        text_input,
+        maybe_cursor_pos,
+        inactive,
+        settings,
+        placeholder,
+        maybe_name,
+        mask_fn,
+        node,
+        target_camera,
+        show_cursor,
+    )) = &query.get(trigger.entity())
+    {
+        // Only apply the minimum width if the user hasn't chosen one for the node themselves.
+        if let Some(min_width) = settings.min_width {
+            commands
+                .entity(*entity)
+                .entry::<Node>()
+                .and_modify(move |mut node| {
+                    if node.min_width == Val::Auto {
+                        node.min_width = min_width;
+                    }
+                });
+        }
+
+        let cursor_pos = match maybe_cursor_pos {
+            None => {
+                let len = text_input.0.chars().count();
+                commands.entity(*entity).insert(TextInputCursorPos(len));
+                len
+            }
+            Some(cursor_pos) => cursor_pos.0,
+        };
+
+        let (value, cursor_pos, mask) =
+            display_value(&text_input.0, cursor_pos, settings, inactive, *mask_fn);
+        let value = masked_value(&value, mask);
+        let values = get_section_values(&value, cursor_pos);
+
+        let scroll = TextInputInnerScroll {
+            cursor_at_end: values.2.is_empty(),
+            ..default()
+        };
+
+        let placeholder_font = placeholder
+            .text_font
+            .clone()
+            .unwrap_or_else(|| font.0.clone());
+
+        let mut placeholder_color = placeholder
+            .text_color
+            .unwrap_or_else(|| placeholder_color(&color.0));
+
+        let placeholder_visible = placeholder.is_visible(text_input, inactive);
+
+        // A hidden placeholder that fades must start transparent, so that it can fade in.
+        if placeholder.fade_duration.is_some() && !placeholder_visible {
+            placeholder_color.0.set_alpha(0.0);
+        }
+
+        let wrap_placeholder = placeholder.overflow == TextInputPlaceholderOverflow::Wrap;
+        let ellipsis_placeholder = placeholder.overflow == TextInputPlaceholderOverflow::Ellipsis;
+
+        // An ellipsized placeholder is also clipped, so that it doesn't spill out before it is first shortened.
+        let clip_placeholder =
+            placeholder.overflow == TextInputPlaceholderOverflow::Clip || ellipsis_placeholder;
+
+        let placeholder_text = commands
+            .spawn((
+                Text::new(&placeholder.value),
+                TextLayout::new_with_linebreak(if wrap_placeholder {
+                    LineBreak::WordBoundary
+                } else {
+                    LineBreak::NoWrap
+                }),
+                placeholder_font,
+                placeholder_color,
+                inner_name(*maybe_name, "TextInputPlaceholderInner"),
+                TextInputPlaceholderInner,
+                if placeholder_visible {
+                    Visibility::Inherited
+                } else {
+                    Visibility::Hidden
+                },
+                Node {
+                    position_type: if clip_placeholder {
+                        PositionType::Relative
+                    } else {
+                        PositionType::Absolute
+                    },
+                    max_width: if wrap_placeholder || ellipsis_placeholder {
+                        Val::Percent(100.)
+                    } else {
+                        Val::Auto
+                    },
+                    ..default()
+                },
+            ))
+            .id();
+
+        if ellipsis_placeholder {
+            commands
+                .entity(placeholder_text)
+                .insert(TextInputPlaceholderEllipsis {
+                    text_input: *entity,
+                });
+        }
+
+        // A node's own text is not clipped, so a clipped placeholder needs a container covering the text input,
+        // with the same padding so that the placeholder lines up with the text. `sync_placeholder_padding` keeps
+        // the padding up to date.
+        let placeholder_root = if clip_placeholder {
+            commands
+                .spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(0.),
+                        right: Val::Px(0.),
+                        top: Val::Px(0.),
+                        bottom: Val::Px(0.),
+                        padding: node.padding,
+                        overflow: Overflow::clip(),
+                        overflow_clip_margin: OverflowClipMargin::content_box(),
+                        ..default()
+                    },
+                    inner_name(*maybe_name, "TextInputPlaceholderContainer"),
+                    TextInputPlaceholderContainer,
+                ))
+                .add_child(placeholder_text)
+                .id()
+        } else {
+            placeholder_text
+        };
+
+        let mut spawned = vec![placeholder_text];
+        if placeholder_root != placeholder_text {
+            spawned.push(placeholder_root);
+        }
+
+        if let Some(inner) = custom_inner {
+            commands.entity(inner).insert(scroll);
+            commands
+                .entity(trigger.entity())
+                .add_child(placeholder_root);
+        } else {
+            let text = commands
+                .spawn((
+                    Text::default(),
+                    TextLayout::new_with_linebreak(LineBreak::NoWrap),
+                    inner_name(*maybe_name, "TextInputInner"),
+                    TextInputInner,
+                    scroll,
+                ))
+                .with_children(|parent| {
+                    // Pre-cursor
+                    parent.spawn((TextSpan::new(values.0), font.0.clone()));
+
+                    // Cursor
+                    parent.spawn((
+                        TextSpan::new(values.1),
+                        TextFont {
+                            font: cursor_font.0.clone(),
+                            ..font.0.clone()
+                        },
+                        if inactive.0 || show_cursor.is_some_and(|show| !show.0) {
+                            TextColor(Color::NONE)
+                        } else {
+                            cursor_color.resolve(color)
+                        },
+                    ));
+
+                    // Post-cursor
+                    parent.spawn((TextSpan::new(values.2), font.0.clone()));
+                })
+                .id();
+
+            let overflow_container = commands
+                .spawn((
+                    Node {
+                        overflow: Overflow::clip(),
+                        // Show the start of the text when scrolling is disabled, and the end otherwise.
+                        justify_content: if settings.disable_scroll {
+                            JustifyContent::FlexStart
+                        } else {
+                            JustifyContent::FlexEnd
+                        },
+                        max_width: Val::Percent(100.),
+                        ..default()
+                    },
+                    inner_name(*maybe_name, "TextInputOverflowContainer"),
+                ))
+                .id();
+
+            commands.entity(overflow_container).add_child(text);
+            commands
+                .entity(trigger.entity())
+                .add_children(&[overflow_container, placeholder_root]);
+
+            spawned.extend([text, overflow_container]);
+        }
+
+        // Bevy only copies `TargetCamera` to new children later in the frame, so set it right away to make sure
+        // that the inner entities are laid out and rendered for the same camera as the text input.
+        if let Some(target_camera) = target_camera {
+            for inner in spawned {
+                commands.entity(inner).insert((*target_camera).clone());
+            }
+        }
+
+        if settings.autofocus {
+            for (other, _, _, _, _, other_inactive, ..) in &query {
+                if other != *entity && !other_inactive.0 {
+                    commands.entity(other).insert(TextInputInactive(true));
+                }
+            }
+
+            if inactive.0 {
+                commands.entity(*entity).insert(TextInputInactive(false));
+            }
+        }
+
+        // Prevent clicks from registering on UI elements underneath the text input.
+        if !settings.preserve_focus_policy {
+            commands.entity(trigger.entity()).insert(FocusPolicy::Block);
+        }
+    }
+}
+
+// Replaces a `TextInputConfig` with a `TextInput` and the components it configures, keeping any that were spawned
+// individually.
+fn apply_config(
+    trigger: Trigger<OnAdd, TextInputConfig>,
+    mut commands: Commands,
+    mut config_query: Query<&mut TextInputConfig>,
+) {
+    let Ok(mut config) = config_query.get_mut(trigger.entity()) else {
+        return;
+    };
+
+    let builder = TextInputBuilder::from(std::mem::take(&mut *config));
+
+    commands
+        .entity(trigger.entity())
+        .insert_if_new(builder.build())
+        .remove::<TextInputConfig>();
+}
+
+// Shows the unmasked value once a `TextInputMaskFn` is removed, which `Changed` filters can't detect.
+fn remove_mask_fn(
+    trigger: Trigger<OnRemove, TextInputMaskFn>,
+    mut cursor_query: Query<&mut TextInputCursorPos>,
+) {
+    if let Ok(mut cursor_pos) = cursor_query.get_mut(trigger.entity()) {
+        cursor_pos.set_changed();
+    }
+}
+
+// Despawns the inner entities of an entity that is no longer a text input.
+fn remove(
+    trigger: Trigger<OnRemove, TextInput>,
+    mut commands: Commands,
+    children_query: Query<&Children>,
+    inner_query: Query<(), Or<(With<TextInputInner>, With<TextInputPlaceholderInner>)>>,
+) {
+    let Ok(children) = children_query.get(trigger.entity()) else {
+        return;
+    };
+
+    // Either the placeholder itself, or the overflow container holding the inner text.
+    for child in children {
+        let is_inner = inner_query.contains(*child)
+            || children_query
+                .iter_descendants(*child)
+                .any(|descendant| inner_query.contains(descendant));
+
+        if is_inner {
+            // The whole entity may be in the middle of being despawned already.
+            commands.entity(*child).try_despawn_recursive();
+        }
+    }
+}
+
+// Shows or hides the cursor based on the text input's [`TextInputInactive`] property.
+fn show_hide_cursor(
+    mut input_query: Query<
+        (
+            Entity,
+            &TextInputTextColor,
+            &mut TextInputCursorTimer,
+            &TextInputInactive,
+            Option<&mut TextInputCursorBlink>,
+            Option<&TextInputShowCursor>,
+        ),
+        Or<(Changed<TextInputInactive>, Changed<TextInputShowCursor>)>,
+    >,
+    inner_text: InnerText,
+    mut writer: TextUiWriter,
+    cursor_color: Res<TextInputCursorColor>,
+) {
+    for (entity, color, mut cursor_timer, inactive, mut blink, show_cursor) in &mut input_query {
+        let Some(inner) = inner_text.inner_entity(entity) else {
+            continue;
+        };
+
+        if let Some(blink) = blink.as_mut() {
+            blink.step = 0;
+        }
+
+        let hidden = inactive.0 || show_cursor.is_some_and(|show| !show.0);
+
+        *writer.color(inner, 1) = if hidden {
+            TextColor(Color::NONE)
+        } else {
+            blink
+                .as_ref()
+                .and_then(|blink| blink.colors.first().copied())
+                .map_or_else(|| cursor_color.resolve(color), TextColor)
+        };
+
+        cursor_timer.timer.reset();
+        cursor_timer.visible = !hidden;
+    }
+}
+
+fn focus_on_click(
+    interaction_query: Query<(Entity, &Interaction, &TextInputSettings), Changed<Interaction>>,
+    mut input_query: Query<(Entity, &TextInputSettings, &mut TextInputInactive)>,
+) {
+    let Some(clicked) = interaction_query
+        .iter()
+        .find(|(_, interaction, settings)| {
+            **interaction == Interaction::Pressed && settings.focus_on_click
+        })
+        .map(|(entity, _, _)| entity)
+    else {
+        return;
+    };
+
+    for (entity, settings, mut inactive) in &mut input_query {
+        if entity == clicked {
+            if inactive.0 {
+                inactive.0 = false;
+            }
+        } else if settings.focus_on_click && !inactive.0 {
+            inactive.0 = true;
+        }
+    }
+}
+
+fn update_focused(
+    mut focused: ResMut<FocusedTextInput>,
+    changed_query: Query<(Entity, &TextInputInactive), Changed<TextInputInactive>>,
+    input_query: Query<(Entity, &TextInputInactive)>,
+) {
+    let mut current = focused.0;
+
+    for (entity, inactive) in &changed_query {
+        if !inactive.0 {
+            current = Some(entity);
+        } else if current == Some(entity) {
+            current = None;
+        }
+    }
+
+    // The focused input may have been despawned, possibly while the plugin was disabled with `TextInputEnabled`.
+    if current.is_some_and(|entity| !input_query.contains(entity)) {
+        current = None;
+    }
+
+    // If the focused input was deactivated, fall back to any other input that is still active.
+    if current.is_none() && focused.0.is_some() {
+        current = input_query
+            .iter()
+            .find(|(_, inactive)| !inactive.0)
+            .map(|(entity, _)| entity);
+    }
+
+    focused.set_if_neq(FocusedTextInput(current));
+}
+
+/// A run condition that returns true if any text input is active.
+///
+/// This can be used to stop other systems from reacting to keyboard input while the user is typing.
+///
+/// # Example
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// use bevy_simple_text_input::any_text_input_focused;
+/// # fn movement() {}
+/// App::new().add_systems(Update, movement.run_if(not(any_text_input_focused)));
+/// ```
+pub fn any_text_input_focused(query: Query<&TextInputInactive>) -> bool {
+    query.iter().any(|inactive| !inactive.0)
+}
+
+// Blinks the cursor on a timer.
+fn blink_cursor(
+    mut input_query: Query<(
+        Entity,
+        &TextInputTextColor,
+        &mut TextInputCursorTimer,
+        Ref<TextInputInactive>,
+        Option<&mut TextInputCursorBlink>,
+        Option<&TextInputShowCursor>,
+    )>,
+    inner_text: InnerText,
+    mut writer: TextUiWriter,
+    time: Res<Time>,
+    cursor_color: Res<TextInputCursorColor>,
+) {
+    for (entity, color, mut cursor_timer, inactive, mut blink, show_cursor) in &mut input_query {
+        if inactive.0 || show_cursor.is_some_and(|show| !show.0) {
+            continue;
+        }
+
+        if cursor_timer.is_changed() && cursor_timer.should_reset {
+            cursor_timer.timer.reset();
+            cursor_timer.should_reset = false;
+            cursor_timer.idle_remaining = cursor_timer.idle_delay;
+
+            if let Some(blink) = blink.as_mut() {
+                blink.step = 0;
+            }
+
+            if let Some(inner) = inner_text.inner_entity(entity) {
+                *writer.color(inner, 1) = blink
+                    .as_ref()
+                    .and_then(|blink| blink.colors.first().copied())
+                    .map_or_else(|| cursor_color.resolve(color), TextColor);
+                cursor_timer.visible = true;
+            };
+
+            continue;
+        }
+
+        if !cursor_timer.idle_remaining.is_zero() {
+            cursor_timer.idle_remaining = cursor_timer.idle_remaining.saturating_sub(time.delta());
+            continue;
+        }
+
+        let finished = cursor_timer.timer.tick(time.delta()).just_finished();
+
+        // Without fading, the cursor only changes when the timer finishes.
+        if !finished && cursor_timer.fade.is_none() {
+            continue;
+        }
+
+        let Some(inner) = inner_text.inner_entity(entity) else {
+            continue;
+        };
+
+        let progress = cursor_timer.fade.map_or(1.0, |fade| {
+            (cursor_timer.timer.elapsed_secs() / fade.as_secs_f32().max(f32::EPSILON)).min(1.0)
+        });
+
+        if let Some(blink) = blink.as_mut().filter(|blink| !blink.colors.is_empty()) {
+            if finished {
+                blink.step = (blink.step + 1) % blink.colors.len();
+            }
+
+            let len = blink.colors.len();
+            let from = blink.colors[(blink.step + len - 1) % len];
+            let to = blink.colors[blink.step];
+
+            cursor_timer.visible = to.alpha() > 0.0;
+
+            let target = TextColor(from.mix(&to, progress));
+            let mut cursor = writer.color(inner, 1);
+            if cursor.0 != target.0 {
+                *cursor = target;
+            }
+
+            continue;
+        }
+
+        if finished {
+            cursor_timer.visible = !cursor_timer.visible;
+        }
+
+        let mut target = cursor_color.resolve(color);
+        let alpha = if cursor_timer.visible {
+            progress
+        } else {
+            1.0 - progress
+        };
+
+        if alpha <= 0.0 {
+            target = TextColor(Color::NONE);
+        } else {
+            target.0.set_alpha(target.alpha() * alpha);
+        }
+
+        let mut cursor = writer.color(inner, 1);
+        if cursor.0 != target.0 {
+            *cursor = target;
+        }
+    }
+}
+
+fn show_hide_placeholder(
+    input_query: Query<
+        (
+            Entity,
+            &TextInputValue,
+            &TextInputInactive,
+            &TextInputPlaceholder,
+        ),
+        Or<(Changed<TextInputValue>, Changed<TextInputInactive>)>,
+    >,
+    mut vis_query: Query<&mut Visibility, With<TextInputPlaceholderInner>>,
+    children_query: Query<&Children>,
+) {
+    for (entity, text, inactive, placeholder) in &input_query {
+        // Fading placeholders are handled by `fade_placeholder`.
+        if placeholder.fade_duration.is_some() {
+            continue;
+        }
+
+        let mut iter = vis_query.iter_many_mut(children_query.iter_descendants(entity));
+        while let Some(mut inner_vis) = iter.fetch_next() {
+            inner_vis.set_if_neq(if placeholder.is_visible(text, inactive) {
+                Visibility::Inherited
+            } else {
+                Visibility::Hidden
+            });
+        }
+    }
+}
+
+fn fade_placeholder(
+    mut fading: Local<HashSet<Entity>>,
+    changed_query: Query<
+        Entity,
+        Or<(
+            Changed<TextInputValue>,
+            Changed<TextInputInactive>,
+            Changed<TextInputPlaceholder>,
+            Changed<TextInputTextColor>,
+        )>,
+    >,
+    input_query: Query<(
+        &TextInputValue,
+        &TextInputInactive,
+        &TextInputPlaceholder,
+        &TextInputTextColor,
+    )>,
+    mut placeholder_query: Query<
+        (&mut TextColor, &mut Visibility),
+        With<TextInputPlaceholderInner>,
+    >,
+    children_query: Query<&Children>,
+    time: Res<Time>,
+) {
+    // Only inputs whose placeholder may not have reached its target alpha yet are visited.
+    fading.extend(&changed_query);
+
+    fading.retain(|&entity| {
+        let Ok((text, inactive, placeholder, color)) = input_query.get(entity) else {
+            return false;
+        };
+        let Some(fade_duration) = placeholder.fade_duration else {
+            return false;
+        };
+
+        let full_alpha = placeholder
+            .text_color
+            .unwrap_or_else(|| placeholder_color(&color.0))
+            .alpha();
+        let target_alpha = if placeholder.is_visible(text, inactive) {
+            full_alpha
+        } else {
+            0.0
+        };
+        let step = full_alpha * time.delta_secs() / fade_duration.as_secs_f32().max(f32::EPSILON);
+
+        let mut done = true;
+        let mut iter = placeholder_query.iter_many_mut(children_query.iter_descendants(entity));
+        while let Some((mut text_color, mut inner_vis)) = iter.fetch_next() {
+            let alpha = text_color.alpha();
+            if alpha < target_alpha {
+                text_color.0.set_alpha((alpha + step).min(target_alpha));
+            } else if alpha > target_alpha {
+                text_color.0.set_alpha((alpha - step).max(target_alpha));
+            }
+
+            inner_vis.set_if_neq(if text_color.alpha() > 0.0 {
+                Visibility::Inherited
+            } else {
+                Visibility::Hidden
+            });
+
+            done &= text_color.alpha() == target_alpha;
+        }
+
+        !done
+    });
+}
+
+fn sync_placeholder_padding(
+    input_query: Query<(&Node, &Children), (With<TextInput>, Changed<Node>)>,
+    mut container_query: Query<
+        &mut Node,
+        (With<TextInputPlaceholderContainer>, Without<TextInput>),
+    >,
+) {
+    for (node, children) in &input_query {
+        let mut containers = container_query.iter_many_mut(children);
+        while let Some(mut container) = containers.fetch_next() {
+            if container.padding != node.padding {
+                container.padding = node.padding;
+            }
+        }
+    }
+}
+
+fn ellipsize_placeholder(
+    input_query: Query<&TextInputPlaceholder>,
+    mut placeholder_query: Query<
+        (
+            Entity,
+            &mut Text,
+            Ref<TextLayoutInfo>,
+            &ComputedNode,
+            &Parent,
+            &TextInputPlaceholderEllipsis,
+        ),
+        With<TextInputPlaceholderInner>,
+    >,
+    container_query: Query<&ComputedNode, Without<TextInputPlaceholderInner>>,
+    // The container width and placeholder text that each placeholder was last shortened for, and whether it is
+    // waiting for the shortened text to be laid out.
+    mut shortened: Local<HashMap<Entity, (f32, String, bool)>>,
+) {
+    for (entity, mut text, layout, node, parent, ellipsis) in &mut placeholder_query {
+        let Ok(placeholder) = input_query.get(ellipsis.text_input) else {
+            continue;
+        };
+        let Ok(container) = container_query.get(parent.get()) else {
+            continue;
+        };
+
+        // Start over from the whole text when there is more or less room, or the text itself changed.
+        let width = container.size().x;
+        let state = shortened
+            .entry(entity)
+            .or_insert_with(|| (width, placeholder.value.clone(), false));
+        if state.0 != width || state.1 != placeholder.value {
+            *state = (width, placeholder.value.clone(), false);
+            if text.0 != placeholder.value {
+                text.0.clone_from(&placeholder.value);
+                state.2 = true;
+            }
+            continue;
+        }
+
+        if state.2 {
+            if !layout.is_changed() {
+                continue;
+            }
+            state.2 = false;
+        }
+
+        // The node is no wider than the content area, while the layout is as wide as the whole text.
+        if let Some(ellipsized) =
+            ellipsize(&placeholder.value, &text.0, node.size().x, layout.size.x)
+        {
+            text.0 = ellipsized;
+            state.2 = true;
+        }
+    }
+
+    if shortened.len() > placeholder_query.iter().len() {
+        shortened.retain(|entity, _| placeholder_query.contains(*entity));
+    }
+}
+
+fn auto_font_size(
+    mut input_query: Query<(
+        Entity,
+        &TextInputAutoFontSize,
+        &ComputedNode,
+        &mut TextInputTextFont,
+    )>,
+    layout: TextInputLayout,
+) {
+    for (entity, auto_size, node, mut font) in &mut input_query {
+        let Some(text_width) = layout.text_width(entity) else {
+            continue;
+        };
+
+        let inset = node.content_inset();
+        let available = (node.size().x - inset.left - inset.right) * node.inverse_scale_factor();
+
+        let font_size = font.0.font_size;
+
+        // Text width is roughly proportional to the font size, so scale the font size by how far the text is
+        // from fitting, in half point steps to avoid toggling between nearly identical sizes.
+        let target = if text_width > 0.0 {
+            (font_size * available / text_width * 2.0).floor() / 2.0
+        } else {
+            auto_size.max
+        };
+        let target = target.clamp(auto_size.min, auto_size.max);
+
+        if target != font_size {
+            font.0.font_size = target;
+        }
+    }
+}
+
+fn update_style(
+    mut input_query: Query<(Entity, &TextInputTextFont), Changed<TextInputTextFont>>,
+    inner_text: InnerText,
+    mut writer: TextUiWriter,
+    cursor_font: Res<TextInputCursorFont>,
+) {
+    for (entity, font) in &mut input_query {
+        let Some(inner) = inner_text.inner_entity(entity) else {
+            continue;
+        };
+
+        *writer.font(inner, 0) = font.0.clone();
+        *writer.font(inner, 1) = TextFont {
+            font: cursor_font.0.clone(),
+            ..font.0.clone()
+        };
+        *writer.font(inner, 2) = font.0.clone();
+    }
+}
+
+fn update_color(
+    input_query: Query<(
+        Entity,
+        &TextInputTextColor,
+        &TextInputInactive,
+        &TextInputCursorTimer,
+        Option<&TextInputCursorBlink>,
+        Option<&TextInputShowCursor>,
+    )>,
+    changed_query: Query<Entity, Changed<TextInputTextColor>>,
+    inner_text: InnerText,
+    mut writer: TextUiWriter,
+    cursor_color: Res<TextInputCursorColor>,
+) {
+    let mut apply = |(entity, color, inactive, cursor_timer, blink, show_cursor): (
+        Entity,
+        &TextInputTextColor,
+        &TextInputInactive,
+        &TextInputCursorTimer,
+        Option<&TextInputCursorBlink>,
+        Option<&TextInputShowCursor>,
+    )| {
+        let Some(inner) = inner_text.inner_entity(entity) else {
+            return;
+        };
+
+        // Keep the cursor in its current blink phase, which `blink_cursor` only updates when the phase changes.
+        let blink_color = blink.and_then(|blink| blink.colors.get(blink.step).copied());
+        let cursor = if inactive.0 || show_cursor.is_some_and(|show| !show.0) {
+            TextColor(Color::NONE)
+        } else if let Some(blink_color) = blink_color {
+            TextColor(blink_color)
+        } else if !cursor_timer.visible {
+            TextColor(Color::NONE)
+        } else {
+            cursor_color.resolve(color)
+        };
+
+        // Only touch spans whose color actually changed. Span 1 is the cursor, and every other span is text.
+        writer.for_each(inner, |_, index, _, _, mut span_color| {
+            let target = if index == 1 { cursor } else { color.0 };
+            if span_color.0 != target.0 {
+                *span_color = target;
+            }
+        });
+    };
+
+    // A new cursor color affects every text input.
+    if cursor_color.is_changed() {
+        input_query.iter().for_each(&mut apply);
+    } else {
+        input_query.iter_many(&changed_query).for_each(&mut apply);
+    }
+}
+
+fn reject_flash(
+    mut input_query: Query<(&mut TextInputRejectFlash, &mut BorderColor)>,
+    time: Res<Time>,
+) {
+    for (mut flash, mut border_color) in &mut input_query {
+        if flash.remaining.is_zero() {
+            continue;
+        }
+
+        // Keep the original color if the flash restarts before it has faded.
+        let restore = *flash.restore.get_or_insert(border_color.0);
+
+        flash.remaining = flash.remaining.saturating_sub(time.delta());
+        if flash.remaining.is_zero() {
+            border_color.0 = restore;
+            flash.restore = None;
+            continue;
+        }
+
+        let progress = flash.remaining.as_secs_f32() / flash.duration.as_secs_f32();
+        border_color.0 = restore.mix(&flash.color, progress);
+    }
+}
+
+fn update_focus_style(
+    mut input_query: Query<
+        (&TextInputFocusStyle, &TextInputInactive, &mut BorderColor),
+        Or<(Changed<TextInputFocusStyle>, Changed<TextInputInactive>)>,
+    >,
+) {
+    for (style, inactive, mut border_color) in &mut input_query {
+        border_color.0 = if inactive.0 {
+            style.inactive
+        } else {
+            style.active
+        };
+    }
+}
+
+fn get_section_values(value: &str, cursor_pos: usize) -> (&str, &'static str, &str) {
+    let (before, after) = value.split_at(byte_pos(value, cursor_pos));
+
+    // If the cursor is between two characters, use the zero-width cursor.
+    let cursor = if after.is_empty() { "}" } else { "|" };
+
+    (before, cursor, after)
+}
+
+fn set_section_value(mut section: Mut<String>, value: &str, mask: Option<char>) {
+    let chars = value.chars().map(|c| mask.unwrap_or(c));
+    if !section.chars().eq(chars.clone()) {
+        section.clear();
+        section.extend(chars);
+    }
+}
+
+// Inserts `text` at a char position, handling control characters according to the settings, removing characters
+// that a numeric input does not accept, and truncating it so that the input stays within `max_length`.
+//
+// Returns the number of chars that were inserted, and whether any chars were rejected by the numeric filter or the
+// length limit.
+fn insert_str_at(
+    input: &mut String,
+    char_pos: usize,
+    text: &str,
+    settings: &TextInputSettings,
+    numeric: Option<&TextInputNumeric>,
+) -> (usize, bool) {
+    let mut text = settings.control_chars.apply(text);
+    let mut rejected = false;
+    if numeric.is_some() && !text.chars().all(TextInputNumeric::accepts) {
+        rejected = true;
+        text = Cow::Owned(
+            text.chars()
+                .filter(|c| TextInputNumeric::accepts(*c))
+                .collect(),
+        );
+    }
+
+    let available = settings
+        .max_length
+        .map_or(usize::MAX, |max| max.saturating_sub(input.chars().count()));
+    let end = byte_pos(&text, available);
+    rejected |= end < text.len();
+    let text = &text[..end];
+
+    input.insert_str(byte_pos(input, char_pos), text);
+
+    (text.chars().count(), rejected)
+}
+
+// Shortens `value` with an ellipsis so that it fits in `available` pixels, given that `displayed`, the whole value or
+// a shortened version of it, is `width` pixels wide. Returns `None` if `displayed` fits or can't be shortened further.
+//
+// The first guess assumes that all graphemes are equally wide. As the guess may still be too wide, calling this
+// again with the laid out width of the result removes one grapheme at a time.
+fn ellipsize(value: &str, displayed: &str, available: f32, width: f32) -> Option<String> {
+    let len = value.graphemes(true).count();
+    if width <= available || len == 0 {
+        return None;
+    }
+
+    let kept = if displayed == value {
+        // Leave room for the ellipsis.
+        ((len as f32 * available / width).floor() as usize).saturating_sub(1)
+    } else {
+        let kept = displayed.strip_suffix('…').unwrap_or(displayed);
+        kept.graphemes(true).count().checked_sub(1)?
+    };
+
+    let mut shortened: String = value.graphemes(true).take(kept.min(len - 1)).collect();
+    shortened.truncate(shortened.trim_end().len());
+    shortened.push('…');
+    Some(shortened)
+}
+
+fn is_whitespace_around(input: &str, char_pos: usize) -> bool {
+    let (before, after) = input.split_at(byte_pos(input, char_pos));
+    before.chars().next_back().is_some_and(char::is_whitespace)
+        || after.chars().next().is_some_and(char::is_whitespace)
+}
+
+// Returns the range of chars in the grapheme cluster containing the char at `char_pos`, so that a user-perceived
+// character such as an emoji with a skin tone modifier is deleted as a whole.
+fn grapheme_at(input: &str, char_pos: usize) -> Range<usize> {
+    let byte = byte_pos(input, char_pos);
+    if byte == input.len() {
+        let len = input.chars().count();
+        return len..len;
+    }
+
+    // Only the text around the position is segmented, so that this stays fast for long values. The whole value is
+    // a single chunk, so the cursor never needs more context.
+    let mut cursor = GraphemeCursor::new(byte, input.len(), true);
+    let start = match cursor.is_boundary(input, 0) {
+        Ok(false) => cursor.prev_boundary(input, 0).ok().flatten().unwrap_or(0),
+        _ => byte,
+    };
+    cursor.set_cursor(start);
+    let end = cursor
+        .next_boundary(input, 0)
+        .ok()
+        .flatten()
+        .unwrap_or(input.len());
+
+    let start_pos = char_pos - input[start..byte].chars().count();
+    start_pos..start_pos + input[start..end].chars().count()
+}
+
+fn remove_chars(input: &mut String, range: Range<usize>) {
+    let start = byte_pos(input, range.start);
+    let end = start + byte_pos(&input[start..], range.len());
+    input.replace_range(start..end, "");
+}
+
+// Swaps the chars before and after `char_pos`, or the last two chars if it is at the end, returning the new cursor
+// position. Does nothing at the start of the text.
+fn transpose_chars(input: &mut String, char_pos: usize) -> Option<usize> {
+    let len = input.chars().count();
+    if len < 2 || char_pos == 0 {
+        return None;
+    }
+
+    let right = char_pos.min(len - 1);
+    let start = byte_pos(input, right - 1);
+    let mid = byte_pos(input, right);
+    let end = byte_pos(input, right + 1);
+
+    let swapped = format!("{}{}", &input[mid..end], &input[start..mid]);
+    input.replace_range(start..end, &swapped);
+
+    Some(right + 1)
+}
+
+// Splits `input` into the words that `WordLeft` and `WordRight` stop at, as char ranges.
+//
+// Words follow Unicode word boundaries, so e.g. "don't" is a single word and each ideograph is a word of its own.
+// Punctuation is part of the word next to it, unless `word_stop` makes runs of punctuation separate words.
+fn words(input: &str, word_stop: TextInputWordStop) -> Vec<Range<usize>> {
+    let mut words: Vec<(Range<usize>, CharClass)> = Vec::new();
+    let mut pos = 0;
+    let mut follows_word = false;
+
+    for (_, segment) in input.split_word_bound_indices() {
+        let start = pos;
+        pos += segment.chars().count();
+
+        if segment.chars().all(char::is_whitespace) {
+            continue;
+        }
+
+        let is_word = segment.chars().any(char::is_alphanumeric);
+
+        // Graphemes are classified as a whole, so that a combining mark stays with its letter.
+        let mut grapheme_start = start;
+        for grapheme in segment.graphemes(true) {
+            let range = grapheme_start..grapheme_start + grapheme.chars().count();
+            grapheme_start = range.end;
+
+            let class = grapheme
+                .chars()
+                .next()
+                .map_or(CharClass::Word, |c| word_stop.class(c));
+
+            // Two adjacent Unicode words, such as two ideographs, stay separate.
+            let separate = range.start == start && is_word && follows_word;
+
+            match words.last_mut() {
+                Some((last, last_class))
+                    if last.end == range.start && *last_class == class && !separate =>
+                {
+                    last.end = range.end;
+                }
+                _ => words.push((range, class)),
+            }
+        }
+
+        follows_word = is_word;
+    }
+
+    words.into_iter().map(|(range, _)| range).collect()
+}
+
+fn word_left(input: &str, char_pos: usize, word_stop: TextInputWordStop) -> usize {
+    words(input, word_stop)
+        .into_iter()
+        .rev()
+        .find(|word| word.start < char_pos)
+        .map_or(0, |word| word.start)
+}
+
+fn word_right(
+    input: &str,
+    char_pos: usize,
+    word_stop: TextInputWordStop,
+    word_right_stop: TextInputWordRightStop,
+) -> usize {
+    let words = words(input, word_stop);
+    let stop = match word_right_stop {
+        TextInputWordRightStop::NextWordStart => words
+            .iter()
+            .find(|word| word.start > char_pos)
+            .map(|word| word.start),
+        TextInputWordRightStop::CurrentWordEnd => words
+            .iter()
+            .find(|word| word.end > char_pos)
+            .map(|word| word.end),
+    };
+
+    stop.unwrap_or_else(|| input.chars().count())
+}
+
+// Combines a dead key with the character typed after it, e.g. `´` and `e` become `é`.
+//
+// If there is no such combination, both characters are kept.
+fn compose_dead_key(dead: char, input: &str) -> String {
+    const COMPOSITIONS: &[(&str, &str, &str)] = &[
+        ("´'", "aeiouyAEIOUY", "áéíóúýÁÉÍÓÚÝ"),
+        ("`", "aeiouAEIOU", "àèìòùÀÈÌÒÙ"),
+        ("^", "aeiouAEIOU", "âêîôûÂÊÎÔÛ"),
+        ("¨\"", "aeiouyAEIOUY", "äëïöüÿÄËÏÖÜŸ"),
+        ("~", "anoANO", "ãñõÃÑÕ"),
+        ("¸", "cC", "çÇ"),
+    ];
+
+    let mut chars = input.chars();
+    if let (Some(base), None) = (chars.next(), chars.next()) {
+        let composed = COMPOSITIONS
+            .iter()
+            .filter(|(dead_keys, _, _)| dead_keys.contains(dead))
+            .find_map(|(_, bases, composed)| {
+                bases
+                    .chars()
+                    .position(|c| c == base)
+                    .and_then(|i| composed.chars().nth(i))
+            });
+
+        if let Some(composed) = composed {
+            return composed.to_string();
+        }
+    }
+
+    let mut uncomposed = dead.to_string();
+    uncomposed.push_str(input);
+    uncomposed
+}
+
+fn byte_pos(input: &str, char_pos: usize) -> usize {
+    let mut char_indices = input.char_indices();
+    char_indices
+        .nth(char_pos)
+        .map(|(pos, _)| pos)
+        .unwrap_or(input.len())
+}
+
+// Returns the text to display for a text input, the cursor position within that text, and the mask character that
+// should still be applied to it.
+fn display_value<'a>(
+    value: &'a str,
+    cursor_pos: usize,
+    settings: &TextInputSettings,
+    inactive: &TextInputInactive,
+    mask_fn: Option<&TextInputMaskFn>,
+) -> (Cow<'a, str>, usize, Option<char>) {
+    match mask_fn {
+        Some(mask_fn) if !settings.revealed(inactive) => {
+            let masked = (mask_fn.0)(value);
+            let masked_len = masked.chars().count();
+            let cursor_pos = if cursor_pos >= value.chars().count() {
+                masked_len
+            } else {
+                cursor_pos.min(masked_len)
+            };
+
+            (Cow::Owned(masked), cursor_pos, None)
+        }
+        _ => match settings.mask(inactive) {
+            Some(mask) if settings.mask_except_last > 0 => {
+                let unmasked_from = value
+                    .chars()
+                    .count()
+                    .saturating_sub(settings.mask_except_last);
+                let masked = value
+                    .chars()
+                    .enumerate()
+                    .map(|(i, c)| if i < unmasked_from { mask } else { c })
+                    .collect();
+
+                (Cow::Owned(masked), cursor_pos, None)
+            }
+            mask => (Cow::Borrowed(value), cursor_pos, mask),
+        },
+    }
+}
+
+fn masked_value(value: &str, mask: Option<char>) -> Cow<'_, str> {
+    mask.map_or(Cow::Borrowed(value), |c| {
+        Cow::Owned(value.chars().map(|_| c).collect::<String>())
+    })
+}
+
+fn is_modifier(key: KeyCode) -> bool {
+    matches!(
+        key,
+        KeyCode::ShiftLeft
+            | KeyCode::ShiftRight
+            | KeyCode::ControlLeft
+            | KeyCode::ControlRight
+            | KeyCode::AltLeft
+            | KeyCode::AltRight
+            | KeyCode::SuperLeft
+            | KeyCode::SuperRight
+    )
+}
+
+// Names an inner entity, prefixed with the text input's own `Name` if it has one.
+fn inner_name(parent_name: Option<&Name>, name: &str) -> Name {
+    match parent_name {
+        Some(parent_name) => Name::new(format!("{parent_name}::{name}")),
+        None => Name::new(name.to_string()),
+    }
+}
+
+fn placeholder_color(color: &TextColor) -> TextColor {
+    TextColor(color.with_alpha(color.alpha() * 0.25))
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::{
+        input::{keyboard::NativeKey, ButtonState, InputPlugin},
+        text::{GlyphAtlasInfo, GlyphAtlasLocation, PositionedGlyph},
+        time::TimeUpdateStrategy,
+    };
+
+    use super::*;
+
+    fn app() -> App {
+        let mut app = base_app();
+        app.add_plugins(TextInputPlugin::default());
+        app
+    }
+
+    // An app that has everything a text input needs except for `TextInputPlugin`.
+    fn base_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            InputPlugin,
+            WindowPlugin {
+                primary_window: None,
+                ..default()
+            },
+        ))
+        .init_asset::<Image>()
+        .init_asset::<TextureAtlasLayout>()
+        .add_plugins(bevy::text::TextPlugin);
+        app
+    }
+
+    fn spawn(app: &mut App, bundle: impl Bundle) -> Entity {
+        let entity = app.world_mut().spawn(bundle).id();
+        app.update();
+        entity
+    }
+
+    fn send_key(app: &mut App, key_code: KeyCode, logical_key: Key, state: ButtonState) {
+        app.world_mut().send_event(KeyboardInput {
+            key_code,
+            logical_key,
+            state,
+            repeat: false,
+            window: Entity::PLACEHOLDER,
+        });
+    }
+
+    fn press(app: &mut App, key_code: KeyCode) {
+        send_key(
+            app,
+            key_code,
+            Key::Unidentified(NativeKey::Unidentified),
+            ButtonState::Pressed,
+        );
+    }
+
+    fn send_text(app: &mut App, text: &str) {
+        send_key(
+            app,
+            KeyCode::KeyA,
+            Key::Character(text.into()),
+            ButtonState::Pressed,
+        );
+    }
+
+    // Types `text` one key at a time.
+    fn type_text(app: &mut App, text: &str) {
+        for c in text.chars() {
+            match c {
+                ' ' => send_key(app, KeyCode::Space, Key::Space, ButtonState::Pressed),
+                c => send_text(app, &c.to_string()),
+            }
+        }
+        app.update();
+    }
+
+    fn set_inactive(app: &mut App, entity: Entity, inactive: bool) {
+        app.world_mut()
+            .get_mut::<TextInputInactive>(entity)
+            .unwrap()
+            .0 = inactive;
+        app.update();
+    }
+
+    // Takes the events that were sent since this was last called.
+    fn events<E: Event>(app: &mut App) -> Vec<E> {
+        app.world_mut()
+            .resource_mut::<Events<E>>()
+            .drain()
+            .collect()
+    }
+
+    fn cursor(app: &App, entity: Entity) -> usize {
+        app.world().get::<TextInputCursorPos>(entity).unwrap().0
+    }
+
+    fn value(app: &App, entity: Entity) -> &str {
+        &app.world().get::<TextInputValue>(entity).unwrap().0
+    }
+
+    fn set_cursor(app: &mut App, entity: Entity, pos: usize) {
+        app.world_mut()
+            .get_mut::<TextInputCursorPos>(entity)
+            .unwrap()
+            .0 = pos;
+        app.update();
+    }
+
+    fn descendant<C: Component>(app: &App, entity: Entity) -> Entity {
+        let world = app.world();
+        let mut stack = vec![entity];
+        while let Some(entity) = stack.pop() {
+            if world.get::<C>(entity).is_some() {
+                return entity;
+            }
+            if let Some(children) = world.get::<Children>(entity) {
+                stack.extend(children.iter());
+            }
+        }
+        panic!("text input has no {} entity", std::any::type_name::<C>());
+    }
+
+    fn count_descendants<C: Component>(app: &App, entity: Entity) -> usize {
+        let world = app.world();
+        world
+            .get::<Children>(entity)
+            .into_iter()
+            .flatten()
+            .map(|child| {
+                usize::from(world.get::<C>(*child).is_some()) + count_descendants::<C>(app, *child)
+            })
+            .sum()
+    }
+
+    fn inner(app: &App, entity: Entity) -> Entity {
+        descendant::<TextInputInner>(app, entity)
+    }
+
+    // Sets the size that UI layout would have computed for a node.
+    fn set_size(app: &mut App, entity: Entity, width: f32) {
+        let mut node = app.world_mut().get_mut::<ComputedNode>(entity).unwrap();
+        *node.get_field_mut::<Vec2>("size").unwrap() = Vec2::new(width, 20.0);
+    }
+
+    // Pretends that the inner text was laid out with the given width, as UI layout would in a real app.
+    fn set_layout(app: &mut App, inner: Entity, width: f32) {
+        set_size(app, inner, width);
+        let mut layout = app.world_mut().get_mut::<TextLayoutInfo>(inner).unwrap();
+        layout.size.x = width;
+        layout.glyphs = vec![PositionedGlyph::new(
+            Vec2::new(width, 0.0),
+            Vec2::ZERO,
+            GlyphAtlasInfo {
+                texture: default(),
+                texture_atlas: default(),
+                location: GlyphAtlasLocation {
+                    glyph_index: 0,
+                    offset: IVec2::ZERO,
+                },
+            },
+            1,
+        )];
+    }
+
+    // The displayed text, including the cursor glyph.
+    fn displayed(app: &App, entity: Entity) -> String {
+        let world = app.world();
+        let inner = inner(app, entity);
+        let mut text = world.get::<Text>(inner).unwrap().0.clone();
+        for child in world.get::<Children>(inner).unwrap() {
+            text.push_str(&world.get::<TextSpan>(*child).unwrap().0);
+        }
+        text
+    }
+
+    // An app that lays out text like a real one, without a window or a GPU.
+    fn layout_app() -> App {
+        use bevy::{
+            asset::RenderAssetUsages,
+            audio::AudioPlugin,
+            log::LogPlugin,
+            render::{
+                render_resource::{Extent3d, TextureDimension, TextureFormat},
+                settings::{RenderCreation, WgpuSettings},
+                RenderPlugin,
+            },
+            window::ExitCondition,
+            winit::WinitPlugin,
+        };
+
+        let mut app = App::new();
+        app.add_plugins(
+            DefaultPlugins
+                .set(WindowPlugin {
+                    primary_window: None,
+                    exit_condition: ExitCondition::DontExit,
+                    ..default()
+                })
+                .set(RenderPlugin {
+                    render_creation: RenderCreation::Automatic(WgpuSettings {
+                        backends: None,
+                        ..default()
+                    }),
+                    ..default()
+                })
+                .disable::<WinitPlugin>()
+                .disable::<AudioPlugin>()
+                .disable::<LogPlugin>(),
+        )
+        .add_plugins(TextInputPlugin::default());
+        app.finish();
+        app.cleanup();
+
+        let image = Image::new_fill(
+            Extent3d {
+                width: 800,
+                height: 600,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            &[0; 4],
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        );
+        let image = app.world_mut().resource_mut::<Assets<Image>>().add(image);
+        app.world_mut().spawn((
+            Camera2d,
+            Camera {
+                target: RenderTarget::Image(image),
+                ..default()
+            },
+            IsDefaultUiCamera,
+        ));
+        app
+    }
+
+    #[test]
+    fn word_navigation_stops_at_whitespace() {
+        let stop = TextInputWordStop::Whitespace;
+        let right = TextInputWordRightStop::NextWordStart;
+
+        assert_eq!(word_right("foo.bar baz", 0, stop, right), 8);
+        assert_eq!(word_right("foo.bar baz", 8, stop, right), 11);
+        assert_eq!(word_left("foo.bar baz", 11, stop), 8);
+        assert_eq!(word_left("foo.bar baz", 8, stop), 0);
+    }
+
+    #[test]
+    fn word_navigation_stops_at_punctuation() {
+        let stop = TextInputWordStop::WhitespaceAndPunctuation;
+        let right = TextInputWordRightStop::NextWordStart;
+
+        assert_eq!(word_right("foo.bar baz", 0, stop, right), 3);
+        assert_eq!(word_right("foo.bar baz", 3, stop, right), 4);
+        assert_eq!(word_right("foo.bar baz", 4, stop, right), 8);
+        assert_eq!(word_left("foo.bar baz", 8, stop), 4);
+        assert_eq!(word_left("foo.bar baz", 4, stop), 3);
+        assert_eq!(word_left("foo.bar baz", 3, stop), 0);
+    }
+
+    #[test]
+    fn word_navigation_follows_unicode_words() {
+        let stop = TextInputWordStop::WhitespaceAndPunctuation;
+        let right = TextInputWordRightStop::NextWordStart;
+
+        // A combining mark doesn't end the word.
+        assert_eq!(word_right("cafe\u{301} au lait", 0, stop, right), 6);
+
+        // Each ideograph is a word of its own, even when only whitespace ends words.
+        let stop = TextInputWordStop::Whitespace;
+        assert_eq!(word_right("日本語", 0, stop, right), 1);
+        assert_eq!(word_left("日本語", 3, stop), 2);
+    }
+
+    #[test]
+    fn word_left_and_right_keys() {
+        let mut app = app();
+        let entity = spawn(
+            &mut app,
+            (TextInput, TextInputValue("foo.bar baz".to_string())),
+        );
+        assert_eq!(cursor(&app, entity), 11);
+
+        press(&mut app, KeyCode::ControlLeft);
+        press(&mut app, KeyCode::ArrowLeft);
+        app.update();
+        assert_eq!(cursor(&app, entity), 8);
+
+        press(&mut app, KeyCode::ArrowLeft);
+        app.update();
+        assert_eq!(cursor(&app, entity), 0);
+
+        press(&mut app, KeyCode::ArrowRight);
+        app.update();
+        assert_eq!(cursor(&app, entity), 8);
+    }
+
+    #[test]
+    fn text_color_change_keeps_cursor_blink_phase() {
+        let cursor_color = |app: &App, entity: Entity| {
+            let world = app.world();
+            let cursor = world.get::<Children>(inner(app, entity)).unwrap()[0];
+            world.get::<TextColor>(cursor).unwrap().0
+        };
+        let set_color = |app: &mut App, entity: Entity, color: Color| {
+            app.world_mut()
+                .get_mut::<TextInputTextColor>(entity)
+                .unwrap()
+                .0 = TextColor(color);
+            app.update();
+        };
+
+        let mut app = app();
+        let entity = spawn(&mut app, TextInput);
+
+        // Hide the cursor as if it had just blinked off.
+        app.world_mut()
+            .get_mut::<TextInputCursorTimer>(entity)
+            .unwrap()
+            .visible = false;
+        let cursor = app.world().get::<Children>(inner(&app, entity)).unwrap()[0];
+        app.world_mut().get_mut::<TextColor>(cursor).unwrap().0 = Color::NONE;
+
+        set_color(&mut app, entity, Color::BLACK);
+        assert_eq!(cursor_color(&app, entity), Color::NONE);
+        let text = app.world().get::<TextColor>(inner(&app, entity)).unwrap().0;
+        assert_eq!(text, Color::BLACK);
+
+        let blink = spawn(
+            &mut app,
+            (
+                TextInput,
+                TextInputCursorBlink::new([Color::WHITE, Color::BLACK]),
+            ),
+        );
+        app.world_mut()
+            .get_mut::<TextInputCursorBlink>(blink)
+            .unwrap()
+            .step = 1;
+        set_color(&mut app, blink, Color::WHITE);
+        assert_eq!(cursor_color(&app, blink), Color::BLACK);
+
+        // Without a blink phase to keep, the cursor takes the new color.
+        app.world_mut()
+            .get_mut::<TextInputCursorTimer>(entity)
+            .unwrap()
+            .visible = true;
+        set_color(&mut app, entity, Color::WHITE);
+        assert_eq!(cursor_color(&app, entity), Color::WHITE);
+    }
+
+    #[test]
+    fn mask_fn_changes_update_displayed_value() {
+        let mut app = app();
+        let entity = spawn(&mut app, (TextInput, TextInputValue("abc".to_string())));
+
+        app.world_mut()
+            .entity_mut(entity)
+            .insert(TextInputMaskFn(|value| value.to_uppercase()));
+        app.update();
+        assert_eq!(displayed(&app, entity), "ABC}");
+
+        app.world_mut()
+            .entity_mut(entity)
+            .insert(TextInputMaskFn(|value| value.replace('b', "*")));
+        app.update();
+        assert_eq!(displayed(&app, entity), "a*c}");
+
+        app.world_mut()
+            .entity_mut(entity)
+            .remove::<TextInputMaskFn>();
+        app.update();
+        assert_eq!(displayed(&app, entity), "abc}");
+    }
+
+    #[test]
+    fn displayed_sections_follow_value_and_cursor() {
+        let mut app = app();
+        let entity = spawn(&mut app, (TextInput, TextInputValue("hello".to_string())));
+        assert_eq!(displayed(&app, entity), "hello}");
+
+        set_cursor(&mut app, entity, 2);
+        assert_eq!(displayed(&app, entity), "he|llo");
+
+        set_cursor(&mut app, entity, 0);
+        assert_eq!(displayed(&app, entity), "|hello");
+
+        app.world_mut().get_mut::<TextInputValue>(entity).unwrap().0 = "héllo wörld".to_string();
+        app.update();
+        assert_eq!(displayed(&app, entity), "héllo wörld}");
+
+        set_cursor(&mut app, entity, 7);
+        assert_eq!(displayed(&app, entity), "héllo w|örld");
+    }
+
+    #[test]
+    fn remove_multi_byte_chars() {
+        let mut input = "héllo wörld".to_string();
+        remove_chars(&mut input, 1..2);
+        assert_eq!(input, "hllo wörld");
+
+        remove_chars(&mut input, 5..7);
+        assert_eq!(input, "hllo rld");
+
+        remove_chars(&mut input, 8..8);
+        assert_eq!(input, "hllo rld");
+    }
+
+    #[test]
+    fn masked_value_replaces_every_char() {
+        assert_eq!(masked_value("pässwörd", None), "pässwörd");
+        assert_eq!(masked_value("pässwörd", Some('*')), "********");
+        assert_eq!(masked_value("", Some('*')), "");
+    }
+
+    #[test]
+    fn delete_multi_byte_chars() {
+        let mut app = app();
+        let entity = spawn(&mut app, (TextInput, TextInputValue("wörd".to_string())));
+        set_cursor(&mut app, entity, 2);
+
+        press(&mut app, KeyCode::Backspace);
+        app.update();
+        assert_eq!(value(&app, entity), "wrd");
+        assert_eq!(cursor(&app, entity), 1);
+
+        app.world_mut().get_mut::<TextInputValue>(entity).unwrap().0 = "wörd".to_string();
+        set_cursor(&mut app, entity, 1);
+        press(&mut app, KeyCode::Delete);
+        app.update();
+        assert_eq!(value(&app, entity), "wrd");
+        assert_eq!(cursor(&app, entity), 1);
+    }
+
+    #[test]
+    fn placeholder_fades_out_and_in() {
+        let mut app = app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            250,
+        )));
+        let entity = spawn(
+            &mut app,
+            (
+                TextInput,
+                TextInputInactive(true),
+                TextInputPlaceholder {
+                    value: "placeholder".to_string(),
+                    fade_duration: Some(Duration::from_secs(1)),
+                    ..default()
+                },
+            ),
+        );
+        let placeholder = descendant::<TextInputPlaceholderInner>(&app, entity);
+        let alpha = |app: &App| app.world().get::<TextColor>(placeholder).unwrap().alpha();
+        let full_alpha = alpha(&app);
+        assert!(full_alpha > 0.0);
+
+        app.world_mut()
+            .get_mut::<TextInputInactive>(entity)
+            .unwrap()
+            .0 = false;
+        app.update();
+        app.update();
+        assert!(alpha(&app) > 0.0 && alpha(&app) < full_alpha);
+
+        for _ in 0..4 {
+            app.update();
+        }
+        assert_eq!(alpha(&app), 0.0);
+        assert_eq!(
+            app.world().get::<Visibility>(placeholder),
+            Some(&Visibility::Hidden)
+        );
+
+        app.world_mut()
+            .get_mut::<TextInputInactive>(entity)
+            .unwrap()
+            .0 = true;
+        for _ in 0..6 {
+            app.update();
+        }
+        assert_eq!(alpha(&app), full_alpha);
+        assert_eq!(
+            app.world().get::<Visibility>(placeholder),
+            Some(&Visibility::Inherited)
+        );
+    }
+
+    #[test]
+    fn compose_dead_keys() {
+        assert_eq!(compose_dead_key('´', "e"), "é");
+        assert_eq!(compose_dead_key('^', "O"), "Ô");
+        assert_eq!(compose_dead_key('~', "n"), "ñ");
+        assert_eq!(compose_dead_key('"', "u"), "ü");
+
+        // Without a combination, both characters are kept.
+        assert_eq!(compose_dead_key('´', "x"), "´x");
+        assert_eq!(compose_dead_key('`', "ab"), "`ab");
+    }
+
+    #[test]
+    fn dead_key_composes_with_next_char() {
+        let mut app = app();
+        let entity = spawn(&mut app, TextInput);
+
+        send_key(
+            &mut app,
+            KeyCode::BracketLeft,
+            Key::Dead(Some('´')),
+            ButtonState::Pressed,
+        );
+        app.update();
+        assert_eq!(value(&app, entity), "");
+
+        send_text(&mut app, "e");
+        app.update();
+        assert_eq!(value(&app, entity), "é");
+        assert_eq!(cursor(&app, entity), 1);
+
+        send_key(
+            &mut app,
+            KeyCode::BracketLeft,
+            Key::Dead(Some('´')),
+            ButtonState::Pressed,
+        );
+        send_key(&mut app, KeyCode::Space, Key::Space, ButtonState::Pressed);
+        app.update();
+        assert_eq!(value(&app, entity), "é´");
+    }
+
+    #[test]
+    fn cursor_persists_across_focus() {
+        let mut app = app();
+        let entity = spawn(&mut app, (TextInput, TextInputValue("hello".to_string())));
+        set_cursor(&mut app, entity, 2);
+
+        set_inactive(&mut app, entity, true);
+        assert_eq!(cursor(&app, entity), 2);
+
+        set_inactive(&mut app, entity, false);
+        assert_eq!(cursor(&app, entity), 2);
+        assert_eq!(displayed(&app, entity), "he|llo");
+    }
+
+    #[test]
+    fn no_scroll_when_text_fits() {
+        let mut app = app();
+        let entity = spawn(&mut app, TextInput);
+        let inner = inner(&app, entity);
+        let container = app.world().get::<Parent>(inner).unwrap().get();
+        let justify_content = app.world().get::<Node>(container).unwrap().justify_content;
+
+        // Left over from scrolling a longer value.
+        app.world_mut().get_mut::<Node>(inner).unwrap().left = Val::Px(-30.0);
+
+        send_text(&mut app, "hi");
+        app.update();
+
+        // The text is laid out in the following frame.
+        set_size(&mut app, container, 100.0);
+        set_size(&mut app, inner, 20.0);
+        app.world_mut()
+            .get_mut::<TextLayoutInfo>(inner)
+            .unwrap()
+            .size
+            .x = 20.0;
+        app.update();
+
+        assert_eq!(value(&app, entity), "hi");
+        assert_eq!(app.world().get::<Node>(inner).unwrap().left, Val::Auto);
+        assert_eq!(
+            app.world().get::<Node>(container).unwrap().justify_content,
+            justify_content
+        );
+    }
+
+    #[test]
+    fn pre_inserted_bindings_survive_build() {
+        let mut app = base_app();
+        app.insert_resource(TextInputNavigationBindings(vec![(
+            TextInputAction::CharLeft,
+            TextInputBinding::new(KeyCode::Home, []),
+        )]))
+        .add_plugins(TextInputPlugin::default());
+
+        assert_eq!(
+            app.world()
+                .resource::<TextInputNavigationBindings>()
+                .0
+                .len(),
+            1
+        );
+
+        let entity = spawn(&mut app, (TextInput, TextInputValue("abc".to_string())));
+        press(&mut app, KeyCode::Home);
+        app.update();
+        assert_eq!(cursor(&app, entity), 2);
+    }
+
+    #[test]
+    fn several_events_in_one_frame() {
+        let mut app = app();
+        let entity = spawn(&mut app, TextInput);
+
+        send_text(&mut app, "a");
+        send_text(&mut app, "b");
+        send_text(&mut app, "c");
+        app.update();
+        assert_eq!(value(&app, entity), "abc");
+        assert_eq!(cursor(&app, entity), 3);
+
+        let changed = events::<TextInputChangedEvent>(&mut app);
+        assert_eq!(changed.len(), 1);
+        assert_eq!(changed[0].value, "abc");
+    }
+
+    #[test]
+    fn submit_mid_frame_uses_value_at_that_point() {
+        let mut app = app();
+        let entity = spawn(&mut app, TextInput);
+
+        send_text(&mut app, "a");
+        send_text(&mut app, "b");
+        press(&mut app, KeyCode::Enter);
+        send_text(&mut app, "c");
+        app.update();
+
+        let submitted = events::<TextInputSubmitEvent>(&mut app);
+        assert_eq!(submitted.len(), 1);
+        assert_eq!(submitted[0].value, "ab");
+        assert_eq!(value(&app, entity), "c");
+        assert_eq!(cursor(&app, entity), 1);
+    }
+
+    #[test]
+    fn insert_str_at_filters_and_truncates() {
+        let settings = TextInputSettings {
+            max_length: Some(6),
+            ..default()
+        };
+
+        let mut input = "wörd".to_string();
+        assert_eq!(
+            insert_str_at(&mut input, 1, "ab", &settings, None),
+            (2, false)
+        );
+        assert_eq!(input, "wabörd");
+
+        // Only as many chars as fit are inserted.
+        let mut input = "wörd".to_string();
+        assert_eq!(
+            insert_str_at(&mut input, 4, "s!?", &settings, None),
+            (2, true)
+        );
+        assert_eq!(input, "wörds!");
+
+        // Control chars are stripped by default.
+        let mut input = String::new();
+        assert_eq!(
+            insert_str_at(&mut input, 0, "a\tb", &settings, None),
+            (2, false)
+        );
+        assert_eq!(input, "ab");
+
+        // A numeric input rejects anything that isn't part of a number.
+        let numeric = TextInputNumeric::default();
+        let mut input = String::new();
+        assert_eq!(
+            insert_str_at(&mut input, 0, "1a.5", &settings, Some(&numeric)),
+            (3, true)
+        );
+        assert_eq!(input, "1.5");
+    }
+
+    #[test]
+    fn overlong_value_is_truncated_on_set() {
+        let mut app = app();
+        let entity = spawn(
+            &mut app,
+            (
+                TextInput,
+                TextInputSettings {
+                    max_length: Some(4),
+                    ..default()
+                },
+            ),
+        );
+
+        app.world_mut().get_mut::<TextInputValue>(entity).unwrap().0 = "wörds".to_string();
+        app.update();
+        assert_eq!(value(&app, entity), "wörd");
+        assert_eq!(cursor(&app, entity), 4);
+
+        let entity = spawn(
+            &mut app,
+            (
+                TextInput,
+                TextInputSettings {
+                    max_length: Some(4),
+                    keep_overlong_value: true,
+                    ..default()
+                },
+            ),
+        );
+        app.world_mut().get_mut::<TextInputValue>(entity).unwrap().0 = "wörds".to_string();
+        app.update();
+        assert_eq!(value(&app, entity), "wörds");
+
+        // No more chars can be typed.
+        send_text(&mut app, "!");
+        app.update();
+        assert_eq!(value(&app, entity), "wörds");
+    }
+
+    #[test]
+    fn rejected_text_sends_reject_event() {
+        let mut app = app();
+        let entity = spawn(
+            &mut app,
+            (
+                TextInput,
+                TextInputNumeric::default(),
+                TextInputSettings {
+                    max_length: Some(2),
+                    ..default()
+                },
+            ),
+        );
+
+        type_text(&mut app, "1");
+        assert!(events::<TextInputRejectEvent>(&mut app).is_empty());
+
+        // Only one event is sent, even though both keystrokes are rejected.
+        type_text(&mut app, "ab");
+        let rejected = events::<TextInputRejectEvent>(&mut app);
+        assert_eq!(rejected.len(), 1);
+        assert_eq!(rejected[0].entity, entity);
+        assert_eq!(value(&app, entity), "1");
+
+        type_text(&mut app, "23");
+        assert_eq!(events::<TextInputRejectEvent>(&mut app).len(), 1);
+        assert_eq!(value(&app, entity), "12");
+    }
+
+    #[test]
+    fn cursor_at_end_scrolls_to_end() {
+        let mut app = app();
+        let entity = spawn(
+            &mut app,
+            (TextInput, TextInputValue("a long value".to_string())),
+        );
+        let inner = inner(&app, entity);
+        let container = app.world().get::<Parent>(inner).unwrap().get();
+        set_size(&mut app, container, 100.0);
+
+        // Scroll to the middle.
+        set_cursor(&mut app, entity, 6);
+        assert!(
+            !app.world()
+                .get::<TextInputInnerScroll>(inner)
+                .unwrap()
+                .cursor_at_end
+        );
+        app.world_mut()
+            .get_mut::<Node>(container)
+            .unwrap()
+            .justify_content = JustifyContent::FlexStart;
+        app.world_mut().get_mut::<Node>(inner).unwrap().left = Val::Px(-50.0);
+
+        // The layout changes in the same frame as the cursor moves to the end.
+        app.world_mut()
+            .get_mut::<TextInputCursorPos>(entity)
+            .unwrap()
+            .0 = 12;
+        set_layout(&mut app, inner, 300.0);
+        app.update();
+
+        assert!(
+            app.world()
+                .get::<TextInputInnerScroll>(inner)
+                .unwrap()
+                .cursor_at_end
+        );
+        assert_eq!(
+            app.world().get::<Node>(container).unwrap().justify_content,
+            JustifyContent::FlexEnd
+        );
+        assert_eq!(app.world().get::<Node>(inner).unwrap().left, Val::Auto);
+    }
+
+    #[test]
+    fn value_and_cursor_set_together() {
+        let mut app = app();
+        let entity = spawn(&mut app, (TextInput, TextInputValue("hello".to_string())));
+
+        let mut entity_mut = app.world_mut().entity_mut(entity);
+        entity_mut.get_mut::<TextInputValue>().unwrap().0 = "hello world".to_string();
+        entity_mut.get_mut::<TextInputCursorPos>().unwrap().0 = 6;
+        app.update();
+        assert_eq!(cursor(&app, entity), 6);
+        assert_eq!(displayed(&app, entity), "hello |world");
+
+        // A cursor past the end of the new value is clamped.
+        let mut entity_mut = app.world_mut().entity_mut(entity);
+        entity_mut.get_mut::<TextInputValue>().unwrap().0 = "hi".to_string();
+        entity_mut.get_mut::<TextInputCursorPos>().unwrap().0 = 6;
+        app.update();
+        assert_eq!(cursor(&app, entity), 2);
+    }
+
+    #[test]
+    fn reinserted_value_does_not_duplicate_inner_entities() {
+        let mut app = app();
+        let entity = spawn(&mut app, TextInput);
+
+        app.world_mut()
+            .entity_mut(entity)
+            .remove::<TextInputValue>();
+        app.update();
+        app.world_mut()
+            .entity_mut(entity)
+            .insert(TextInputValue("again".to_string()));
+        app.update();
+
+        assert_eq!(count_descendants::<TextInputInner>(&app, entity), 1);
+        assert_eq!(
+            count_descendants::<TextInputPlaceholderInner>(&app, entity),
+            1
+        );
+        assert_eq!(displayed(&app, entity), "again}");
+    }
+
+    #[test]
+    fn removing_text_input_despawns_inner_entities() {
+        let mut app = app();
+        let entity = spawn(&mut app, TextInput);
+        assert_eq!(count_descendants::<TextInputInner>(&app, entity), 1);
+
+        app.world_mut().entity_mut(entity).remove::<TextInput>();
+        app.update();
+
+        assert_eq!(count_descendants::<TextInputInner>(&app, entity), 0);
+        assert_eq!(
+            count_descendants::<TextInputPlaceholderInner>(&app, entity),
+            0
+        );
+        assert!(app
+            .world()
+            .get::<Children>(entity)
+            .is_none_or(|c| c.is_empty()));
+    }
+
+    #[test]
+    fn key_events_are_opt_in() {
+        let mut app = app();
+        let without = spawn(&mut app, TextInput);
+        let with = spawn(&mut app, (TextInput, TextInputKeyEvents));
+
+        send_text(&mut app, "a");
+        press(&mut app, KeyCode::ArrowLeft);
+        app.update();
+        assert_eq!(value(&app, without), "a");
+
+        let events = events::<TextInputKeyEvent>(&mut app);
+        assert_eq!(events.len(), 2);
+        assert!(events.iter().all(|event| event.entity == with));
+        assert!(matches!(&events[0].action, TextInputAction::InsertText(text) if text == "a"));
+        assert!(matches!(events[1].action, TextInputAction::CharLeft));
+    }
+
+    #[test]
+    fn char_left_and_right_step_over_graphemes() {
+        let mut app = app();
+        let entity = spawn(
+            &mut app,
+            (TextInput, TextInputValue("e\u{301}👍🏽x".to_string())),
+        );
+        assert_eq!(cursor(&app, entity), 5);
+
+        press(&mut app, KeyCode::ArrowLeft);
+        app.update();
+        assert_eq!(cursor(&app, entity), 4);
+
+        press(&mut app, KeyCode::ArrowLeft);
+        app.update();
+        assert_eq!(cursor(&app, entity), 2);
+
+        press(&mut app, KeyCode::ArrowLeft);
+        app.update();
+        assert_eq!(cursor(&app, entity), 0);
+
+        press(&mut app, KeyCode::ArrowRight);
+        app.update();
+        assert_eq!(cursor(&app, entity), 2);
+
+        press(&mut app, KeyCode::ArrowRight);
+        app.update();
+        assert_eq!(cursor(&app, entity), 4);
+    }
+
+    #[test]
+    fn cursor_snaps_to_grapheme_start() {
+        let mut app = app();
+        let entity = spawn(
+            &mut app,
+            (TextInput, TextInputValue("ae\u{301}b".to_string())),
+        );
+
+        set_cursor(&mut app, entity, 2);
+        assert_eq!(cursor(&app, entity), 1);
+
+        // Also when the value changes under the cursor.
+        let mut entity_mut = app.world_mut().entity_mut(entity);
+        entity_mut.get_mut::<TextInputValue>().unwrap().0 = "👍🏽".to_string();
+        entity_mut.get_mut::<TextInputCursorPos>().unwrap().0 = 1;
+        app.update();
+        assert_eq!(cursor(&app, entity), 0);
+    }
+
+    #[test]
+    fn control_chars_in_pasted_text() {
+        let text = "a\tb\u{c}c";
+        assert_eq!(TextInputControlChars::Strip.apply(text), "abc");
+        assert_eq!(TextInputControlChars::Replace(' ').apply(text), "a b c");
+        assert_eq!(TextInputControlChars::Keep.apply(text), text);
+
+        let mut app = app();
+        let entity = spawn(
+            &mut app,
+            (
+                TextInput,
+                TextInputSettings {
+                    control_chars: TextInputControlChars::Replace(' '),
+                    ..default()
+                },
+            ),
+        );
+        send_text(&mut app, text);
+        app.update();
+        assert_eq!(value(&app, entity), "a b c");
+        assert_eq!(cursor(&app, entity), 5);
+    }
+
+    #[test]
+    fn numeric_input_filters_and_clamps() {
+        let mut app = app();
+        let numeric = TextInputNumeric {
+            min: Some(0.0),
+            max: Some(10.0),
+            ..default()
+        };
+        let entity = spawn(
+            &mut app,
+            (
+                TextInput,
+                numeric,
+                TextInputSettings {
+                    retain_on_submit: true,
+                    ..default()
+                },
+            ),
+        );
+
+        send_text(&mut app, "1a5");
+        app.update();
+        assert_eq!(value(&app, entity), "15");
+
+        press(&mut app, KeyCode::Enter);
+        app.update();
+        assert_eq!(value(&app, entity), "10");
+        assert_eq!(
+            app.world().get::<TextInputNumericValue>(entity),
+            Some(&TextInputNumericValue(Some(10.0)))
+        );
+
+        let mut value = TextInputValue("abc".to_string());
+        assert_eq!(numeric.step(&mut value, -1.0), 0.0);
+        assert_eq!(numeric.step(&mut value, 2.5), 2.5);
+        assert_eq!(value.0, "2.5");
+    }
+
+    #[test]
+    fn word_count_follows_unicode_words() {
+        let count = |value: &str| TextInputValue(value.to_string()).word_count();
+        assert_eq!(count(""), 0);
+        assert_eq!(count("  \t "), 0);
+        assert_eq!(count("hello, wörld!"), 2);
+        assert_eq!(count("it's 3.5 - ok"), 3);
+        assert_eq!(count("日本語"), 3);
+    }
+
+    #[test]
+    fn bound_printable_key_does_not_insert() {
+        let mut app = app();
+        app.insert_resource(TextInputNavigationBindings(vec![(
+            TextInputAction::LineStart,
+            TextInputBinding::new(KeyCode::KeyH, []),
+        )]));
+        let entity = spawn(&mut app, (TextInput, TextInputValue("abc".to_string())));
+
+        send_key(
+            &mut app,
+            KeyCode::KeyH,
+            Key::Character("h".into()),
+            ButtonState::Pressed,
+        );
+        app.update();
+        assert_eq!(value(&app, entity), "abc");
+        assert_eq!(cursor(&app, entity), 0);
+
+        // Unbound keys are still inserted.
+        send_key(
+            &mut app,
+            KeyCode::KeyJ,
+            Key::Character("j".into()),
+            ButtonState::Pressed,
+        );
+        app.update();
+        assert_eq!(value(&app, entity), "jabc");
+    }
+
+    #[test]
+    fn ellipsize_shortens_to_fit() {
+        let value = "Enter your full name";
+        assert_eq!(ellipsize(value, value, 100.0, 100.0), None);
+
+        // Half of the graphemes fit, minus one for the ellipsis.
+        assert_eq!(
+            ellipsize(value, value, 50.0, 100.0).as_deref(),
+            Some("Enter you…")
+        );
+
+        // Then one grapheme at a time is removed, along with trailing whitespace.
+        assert_eq!(
+            ellipsize(value, "Enter you…", 50.0, 55.0).as_deref(),
+            Some("Enter yo…")
+        );
+        assert_eq!(
+            ellipsize(value, "Enter y…", 50.0, 55.0).as_deref(),
+            Some("Enter…")
+        );
+        assert_eq!(ellipsize(value, "E…", 5.0, 10.0).as_deref(), Some("…"));
+        assert_eq!(ellipsize(value, "…", 5.0, 10.0), None);
+
+        // Graphemes are kept whole.
+        assert_eq!(
+            ellipsize(
+                "e\u{301}e\u{301}e\u{301}e\u{301}",
+                "e\u{301}e\u{301}…",
+                50.0,
+                60.0
+            )
+            .as_deref(),
+            Some("e\u{301}…")
+        );
+    }
+
+    #[test]
+    fn min_width_only_applies_to_auto_min_width() {
+        let mut app = app();
+        let settings = || TextInputSettings {
+            min_width: Some(Val::Px(100.)),
+            ..default()
+        };
+        let entity = spawn(
+            &mut app,
+            (
+                TextInput,
+                settings(),
+                Node {
+                    width: Val::Px(50.),
+                    ..default()
+                },
+            ),
+        );
+        let node = app.world().get::<Node>(entity).unwrap();
+        assert_eq!(node.min_width, Val::Px(100.));
+        assert_eq!(node.width, Val::Px(50.));
+
+        let entity = spawn(
+            &mut app,
+            (
+                TextInput,
+                settings(),
+                Node {
+                    min_width: Val::Px(20.),
+                    ..default()
+                },
+            ),
+        );
+        assert_eq!(
+            app.world().get::<Node>(entity).unwrap().min_width,
+            Val::Px(20.)
+        );
+    }
+
+    #[test]
+    fn clipped_placeholder_follows_padding() {
+        let mut app = app();
+        let entity = spawn(
+            &mut app,
+            (
+                TextInput,
+                Node {
+                    padding: UiRect::all(Val::Px(5.)),
+                    ..default()
+                },
+                TextInputPlaceholder {
+                    value: "placeholder".to_string(),
+                    overflow: TextInputPlaceholderOverflow::Clip,
+                    ..default()
+                },
+            ),
+        );
+        let container = descendant::<TextInputPlaceholderContainer>(&app, entity);
+        assert_eq!(
+            app.world().get::<Node>(container).unwrap().padding,
+            UiRect::all(Val::Px(5.))
+        );
+
+        app.world_mut().get_mut::<Node>(entity).unwrap().padding = UiRect::horizontal(Val::Px(8.));
+        app.update();
+        assert_eq!(
+            app.world().get::<Node>(container).unwrap().padding,
+            UiRect::horizontal(Val::Px(8.))
+        );
+    }
+
+    #[test]
+    fn placeholder_ellipsis() {
+        let mut app = app();
+        let value = "Enter your full name";
+        let entity = spawn(
+            &mut app,
+            (
+                TextInput,
+                TextInputInactive(true),
+                TextInputPlaceholder {
+                    value: value.to_string(),
+                    overflow: TextInputPlaceholderOverflow::Ellipsis,
+                    ..default()
+                },
+            ),
+        );
+        let placeholder = descendant::<TextInputPlaceholderInner>(&app, entity);
+        let container = app.world().get::<Parent>(placeholder).unwrap().get();
+        let text = |app: &App| app.world().get::<Text>(placeholder).unwrap().0.clone();
+
+        // Pretends that UI layout sized the placeholder, which is no wider than the container.
+        let layout = |app: &mut App, container_width: f32, node_width: f32, text_width: f32| {
+            set_size(app, container, container_width);
+            set_size(app, placeholder, node_width);
+            app.world_mut()
+                .get_mut::<TextLayoutInfo>(placeholder)
+                .unwrap()
+                .size
+                .x = text_width;
+            app.update();
+        };
+
+        layout(&mut app, 100.0, 100.0, 200.0);
+        assert_eq!(text(&app), value);
+        layout(&mut app, 100.0, 100.0, 200.0);
+        assert_eq!(text(&app), "Enter you…");
+
+        // The first guess was still too wide.
+        layout(&mut app, 100.0, 100.0, 110.0);
+        assert_eq!(text(&app), "Enter yo…");
+        layout(&mut app, 100.0, 90.0, 90.0);
+        assert_eq!(text(&app), "Enter yo…");
+
+        // There is room for the whole text again.
+        layout(&mut app, 300.0, 90.0, 90.0);
+        assert_eq!(text(&app), value);
+    }
+
+    #[test]
+    fn no_scroll_marker_leaves_layout_alone() {
+        let mut app = app();
+        let scrolled = spawn(
+            &mut app,
+            (TextInput, TextInputValue("a long value".to_string())),
+        );
+        let unscrolled = spawn(
+            &mut app,
+            (
+                TextInput,
+                TextInputValue("a long value".to_string()),
+                TextInputNoScroll,
+            ),
+        );
+
+        let scroll = |app: &mut App, entity: Entity| {
+            let inner = inner(app, entity);
+            let container = app.world().get::<Parent>(inner).unwrap().get();
+            set_size(app, container, 100.0);
+            app.world_mut()
+                .get_mut::<Node>(container)
+                .unwrap()
+                .justify_content = JustifyContent::FlexStart;
+            app.world_mut().get_mut::<Node>(inner).unwrap().left = Val::Px(-30.0);
+            set_layout(app, inner, 300.0);
+            (inner, container)
+        };
+        let (scrolled_inner, scrolled_container) = scroll(&mut app, scrolled);
+        let (unscrolled_inner, unscrolled_container) = scroll(&mut app, unscrolled);
+        app.update();
+
+        let node = |app: &App, entity: Entity| app.world().get::<Node>(entity).unwrap().clone();
+        assert_eq!(node(&app, scrolled_inner).left, Val::Auto);
+        assert_eq!(
+            node(&app, scrolled_container).justify_content,
+            JustifyContent::FlexEnd
+        );
+        assert_eq!(node(&app, unscrolled_inner).left, Val::Px(-30.0));
+        assert_eq!(
+            node(&app, unscrolled_container).justify_content,
+            JustifyContent::FlexStart
+        );
+    }
+
+    #[test]
+    fn edit_in_place_submits_once_when_blurred() {
+        let mut app = app();
+        let entity = spawn(
+            &mut app,
+            (
+                TextInput,
+                TextInputInactive(true),
+                TextInputValue("cell".to_string()),
+                TextInputEditInPlace,
+            ),
+        );
+        assert!(!app.world().get::<TextInputInactive>(entity).unwrap().0);
+        assert!(events::<TextInputSubmitEvent>(&mut app).is_empty());
+
+        set_inactive(&mut app, entity, true);
+        let submitted = events::<TextInputSubmitEvent>(&mut app);
+        assert_eq!(submitted.len(), 1);
+        assert_eq!(submitted[0].value, "cell");
+
+        // Writing the same value again is not a blur.
+        set_inactive(&mut app, entity, true);
+        assert!(events::<TextInputSubmitEvent>(&mut app).is_empty());
+
+        // Neither is focusing it again.
+        set_inactive(&mut app, entity, false);
+        assert!(events::<TextInputSubmitEvent>(&mut app).is_empty());
+    }
+
+    #[test]
+    fn word_right_stops() {
+        let stop = TextInputWordStop::Whitespace;
+
+        let right = TextInputWordRightStop::NextWordStart;
+        assert_eq!(word_right("foo bar", 0, stop, right), 4);
+        assert_eq!(word_right("foo bar", 4, stop, right), 7);
+
+        let right = TextInputWordRightStop::CurrentWordEnd;
+        assert_eq!(word_right("foo bar", 0, stop, right), 3);
+        assert_eq!(word_right("foo bar", 3, stop, right), 7);
+        assert_eq!(word_right("foo bar", 7, stop, right), 7);
+
+        let mut app = app();
+        let entity = spawn(
+            &mut app,
+            (
+                TextInput,
+                TextInputValue("foo bar".to_string()),
+                TextInputSettings {
+                    word_right_stop: TextInputWordRightStop::CurrentWordEnd,
+                    ..default()
+                },
+            ),
+        );
+        set_cursor(&mut app, entity, 0);
+        press(&mut app, KeyCode::ControlLeft);
+        press(&mut app, KeyCode::ArrowRight);
+        app.update();
+        assert_eq!(cursor(&app, entity), 3);
+    }
+
+    #[test]
+    fn held_enter_submits_once() {
+        let mut app = app();
+        let entity = spawn(
+            &mut app,
+            (
+                TextInput,
+                TextInputValue("hi".to_string()),
+                TextInputSettings {
+                    retain_on_submit: true,
+                    ..default()
+                },
+            ),
+        );
+
+        press(&mut app, KeyCode::Enter);
+        for _ in 0..3 {
+            app.world_mut().send_event(KeyboardInput {
+                key_code: KeyCode::Enter,
+                logical_key: Key::Enter,
+                state: ButtonState::Pressed,
+                repeat: true,
+                window: Entity::PLACEHOLDER,
+            });
+        }
+        app.update();
+
+        let submitted = events::<TextInputSubmitEvent>(&mut app);
+        assert_eq!(submitted.len(), 1);
+        assert_eq!(submitted[0].entity, entity);
+    }
+
+    #[test]
+    fn children_inherit_target_camera() {
+        let mut app = app();
+        let camera = app.world_mut().spawn_empty().id();
+        let entity = spawn(&mut app, (TextInput, TargetCamera(camera)));
+
+        let inner = inner(&app, entity);
+        let container = app.world().get::<Parent>(inner).unwrap().get();
+        let placeholder = descendant::<TextInputPlaceholderInner>(&app, entity);
+        for child in [inner, container, placeholder] {
+            assert_eq!(
+                app.world()
+                    .get::<TargetCamera>(child)
+                    .map(TargetCamera::entity),
+                Some(camera)
+            );
+        }
+    }
+
+    #[test]
+    fn transpose_chars_at_edges() {
+        let transpose = |value: &str, pos: usize| {
+            let mut value = value.to_string();
+            transpose_chars(&mut value, pos).map(|pos| (value, pos))
+        };
+
+        assert_eq!(transpose("abc", 0), None);
+        assert_eq!(transpose("a", 1), None);
+        assert_eq!(transpose("", 0), None);
+        assert_eq!(transpose("abc", 1), Some(("bac".to_string(), 2)));
+
+        // At the end, the last two chars are swapped.
+        assert_eq!(transpose("abc", 3), Some(("acb".to_string(), 3)));
+        assert_eq!(transpose("äöü", 2), Some(("äüö".to_string(), 3)));
+    }
+
+    #[test]
+    fn transpose_key() {
+        let mut app = app();
+        app.insert_resource(TextInputNavigationBindings(vec![(
+            TextInputAction::Transpose,
+            TextInputBinding::new(KeyCode::KeyT, []),
+        )]));
+        let entity = spawn(&mut app, (TextInput, TextInputValue("abcd".to_string())));
+        set_cursor(&mut app, entity, 1);
+
+        press(&mut app, KeyCode::KeyT);
+        app.update();
+        assert_eq!(value(&app, entity), "bacd");
+        assert_eq!(cursor(&app, entity), 2);
+
+        press(&mut app, KeyCode::KeyT);
+        app.update();
+        assert_eq!(value(&app, entity), "bcad");
+        assert_eq!(cursor(&app, entity), 3);
+    }
+
+    #[test]
+    fn kill_and_yank_round_trip() {
+        let mut app = app();
+        app.insert_resource(TextInputNavigationBindings(vec![
+            (
+                TextInputAction::KillToLineEnd,
+                TextInputBinding::new(KeyCode::KeyK, []),
+            ),
+            (
+                TextInputAction::KillToLineStart,
+                TextInputBinding::new(KeyCode::KeyU, []),
+            ),
+            (
+                TextInputAction::Yank,
+                TextInputBinding::new(KeyCode::KeyY, []),
+            ),
+        ]));
+        let entity = spawn(
+            &mut app,
+            (
+                TextInput,
+                TextInputValue("héllo wörld".to_string()),
+                TextInputKillRing::default(),
+            ),
+        );
+        set_cursor(&mut app, entity, 6);
+
+        press(&mut app, KeyCode::KeyK);
+        app.update();
+        assert_eq!(value(&app, entity), "héllo ");
+        assert_eq!(cursor(&app, entity), 6);
+
+        press(&mut app, KeyCode::KeyU);
+        app.update();
+        assert_eq!(value(&app, entity), "");
+
+        // The most recently killed text is yanked first.
+        press(&mut app, KeyCode::KeyY);
+        press(&mut app, KeyCode::KeyY);
+        app.update();
+        assert_eq!(value(&app, entity), "héllo héllo ");
+        assert_eq!(cursor(&app, entity), 12);
+
+        let kill_ring = app.world().get::<TextInputKillRing>(entity).unwrap();
+        assert_eq!(kill_ring.entries, ["wörld", "héllo "]);
+    }
+
+    #[test]
+    fn config_is_applied_unless_components_are_spawned() {
+        let mut app = app();
+        let entity = spawn(
+            &mut app,
+            (
+                TextInputConfig {
+                    text_color: TextColor(Color::WHITE),
+                    placeholder: TextInputPlaceholder {
+                        value: "placeholder".to_string(),
+                        ..default()
+                    },
+                    settings: TextInputSettings {
+                        max_length: Some(3),
+                        ..default()
+                    },
+                    ..default()
+                },
+                TextInputTextColor(TextColor(Color::BLACK)),
+                TextInputValue("ab".to_string()),
+            ),
+        );
+
+        let world = app.world();
+        assert!(world.get::<TextInput>(entity).is_some());
+        assert!(world.get::<TextInputConfig>(entity).is_none());
+        assert_eq!(
+            world.get::<TextInputTextColor>(entity).unwrap().0 .0,
+            Color::BLACK
+        );
+        assert_eq!(
+            world.get::<TextInputSettings>(entity).unwrap().max_length,
+            Some(3)
+        );
+        assert_eq!(
+            world.get::<TextInputPlaceholder>(entity).unwrap().value,
+            "placeholder"
+        );
+        assert_eq!(value(&app, entity), "ab");
+    }
+
+    #[test]
+    fn keys_pressed_while_disabled_are_discarded() {
+        let mut app = app();
+        let entity = spawn(&mut app, TextInput);
+
+        app.world_mut().resource_mut::<TextInputEnabled>().0 = false;
+        type_text(&mut app, "ab");
+        app.world_mut().resource_mut::<TextInputEnabled>().0 = true;
+        app.update();
+        assert_eq!(value(&app, entity), "");
+
+        type_text(&mut app, "c");
+        assert_eq!(value(&app, entity), "c");
+    }
+
+    #[test]
+    fn history_bindings_need_submit_history() {
+        let mut app = app();
+        let settings = || TextInputSettings {
+            consume_input: true,
+            ..default()
+        };
+        let plain = spawn(&mut app, (TextInput, TextInputKeyEvents, settings()));
+
+        press(&mut app, KeyCode::ArrowUp);
+        app.update();
+        assert!(app
+            .world()
+            .resource::<ButtonInput<KeyCode>>()
+            .pressed(KeyCode::ArrowUp));
+        assert!(events::<TextInputKeyEvent>(&mut app).is_empty());
+
+        set_inactive(&mut app, plain, true);
+        let mut history = TextInputSubmitHistory::new(10);
+        history.push("first");
+        let entity = spawn(&mut app, (TextInput, history, settings()));
+
+        press(&mut app, KeyCode::ArrowDown);
+        press(&mut app, KeyCode::ArrowUp);
+        app.update();
+        assert!(!app
+            .world()
+            .resource::<ButtonInput<KeyCode>>()
+            .pressed(KeyCode::ArrowUp));
+        assert_eq!(value(&app, entity), "first");
+        assert_eq!(cursor(&app, entity), 5);
+    }
+
+    #[test]
+    fn game_input_plugin_hides_typed_keys() {
+        let mut app = app();
+        app.add_plugins(TextInputGameInputPlugin {
+            allowed_keys: vec![KeyCode::Escape],
+        });
+        let entity = spawn(&mut app, TextInput);
+
+        press(&mut app, KeyCode::ControlLeft);
+        press(&mut app, KeyCode::KeyW);
+        press(&mut app, KeyCode::Escape);
+        app.update();
+        let keys = app.world().resource::<ButtonInput<KeyCode>>();
+        assert!(!keys.pressed(KeyCode::KeyW));
+        assert!(keys.pressed(KeyCode::ControlLeft));
+        assert!(keys.just_pressed(KeyCode::Escape));
+
+        set_inactive(&mut app, entity, true);
+        press(&mut app, KeyCode::KeyA);
+        app.update();
+        assert!(app
+            .world()
+            .resource::<ButtonInput<KeyCode>>()
+            .just_pressed(KeyCode::KeyA));
+
+        // Keys aren't hidden while the plugin is disabled, even if a text input is focused.
+        set_inactive(&mut app, entity, false);
+        app.world_mut().resource_mut::<TextInputEnabled>().0 = false;
+        press(&mut app, KeyCode::KeyS);
+        app.update();
+        assert!(app
+            .world()
+            .resource::<ButtonInput<KeyCode>>()
+            .just_pressed(KeyCode::KeyS));
+    }
+
+    #[test]
+    fn auto_capitalize_sentences() {
+        let sentences = TextInputAutoCapitalize::Sentences;
+        assert_eq!(sentences.apply("", "h").as_deref(), Some("H"));
+        assert_eq!(sentences.apply("Hi. ", "t").as_deref(), Some("T"));
+        assert_eq!(sentences.apply("Hi!  ", "é").as_deref(), Some("É"));
+        assert_eq!(sentences.apply("Hi?", "t"), None);
+        assert_eq!(sentences.apply("3.5 ", "t"), None);
+        assert_eq!(sentences.apply("Hi, ", "t"), None);
+        assert_eq!(sentences.apply("", "1"), None);
+        assert_eq!(TextInputAutoCapitalize::None.apply("", "h"), None);
+
+        let mut app = app();
+        let entity = spawn(
+            &mut app,
+            (
+                TextInput,
+                TextInputSettings {
+                    auto_capitalize: sentences,
+                    ..default()
+                },
+            ),
+        );
+        type_text(&mut app, "hi. there");
+        assert_eq!(value(&app, entity), "Hi. There");
+
+        // A letter that the user lowercased again is left alone.
+        app.world_mut().get_mut::<TextInputValue>(entity).unwrap().0 = "Hi.".to_string();
+        app.update();
+        type_text(&mut app, " t");
+        assert_eq!(value(&app, entity), "Hi. T");
+        press(&mut app, KeyCode::Backspace);
+        app.update();
+        type_text(&mut app, "t");
+        assert_eq!(value(&app, entity), "Hi. t");
+    }
+
+    #[test]
+    fn empty_submit() {
+        for ignore_empty_submit in [false, true] {
+            let mut app = app();
+            spawn(
+                &mut app,
+                (
+                    TextInput,
+                    TextInputValue(" ".to_string()),
+                    TextInputSettings {
+                        ignore_empty_submit,
+                        ..default()
+                    },
+                ),
+            );
+
+            press(&mut app, KeyCode::Enter);
+            app.update();
+            assert_eq!(
+                events::<TextInputSubmitEvent>(&mut app).len(),
+                usize::from(!ignore_empty_submit)
+            );
+        }
+    }
+
+    #[test]
+    fn dirty_flag() {
+        let mut app = app();
+        let entity = spawn(&mut app, (TextInput, TextInputDirty::default()));
+        let dirty = |app: &App| app.world().get::<TextInputDirty>(entity).unwrap().0;
+
+        // Setting the value programmatically is not an edit.
+        app.world_mut().get_mut::<TextInputValue>(entity).unwrap().0 = "saved".to_string();
+        app.update();
+        assert!(!dirty(&app));
+
+        // Neither is moving the cursor.
+        press(&mut app, KeyCode::ArrowLeft);
+        app.update();
+        assert!(!dirty(&app));
+
+        type_text(&mut app, "!");
+        assert!(dirty(&app));
+
+        // Apps can reset it, e.g. after saving.
+        app.world_mut().get_mut::<TextInputDirty>(entity).unwrap().0 = false;
+        app.update();
+        assert!(!dirty(&app));
+
+        press(&mut app, KeyCode::Backspace);
+        app.update();
+        assert!(dirty(&app));
+
+        press(&mut app, KeyCode::Enter);
+        app.update();
+        assert!(!dirty(&app));
+    }
+
+    #[test]
+    fn whitespace_settings() {
+        let mut app = app();
+        let entity = spawn(
+            &mut app,
+            (
+                TextInput,
+                TextInputSettings {
+                    trim_leading_spaces: true,
+                    collapse_spaces: true,
+                    shift_space: Some('\u{a0}'),
+                    ..default()
+                },
+            ),
+        );
+
+        type_text(&mut app, "  a  b ");
+        assert_eq!(value(&app, entity), "a b ");
+
+        // Also when the cursor is moved back to the start.
+        set_cursor(&mut app, entity, 0);
+        type_text(&mut app, " ");
+        assert_eq!(value(&app, entity), "a b ");
+
+        set_cursor(&mut app, entity, 4);
+        type_text(&mut app, "c");
+        press(&mut app, KeyCode::ShiftLeft);
+        type_text(&mut app, " ");
+        assert_eq!(value(&app, entity), "a b c\u{a0}");
+    }
 
-                match camera.target {
-                    RenderTarget::Window(window_ref) => Some(window_ref),
-                    _ => None,
-                }
-            }
-            None => Some(WindowRef::Primary),
+    #[test]
+    fn autofocus_leaves_one_input_active() {
+        let mut app = app();
+        let autofocus = || TextInputSettings {
+            autofocus: true,
+            ..default()
         };
+        let inputs = [
+            app.world_mut().spawn(TextInput).id(),
+            app.world_mut()
+                .spawn((TextInput, TextInputInactive(true), autofocus()))
+                .id(),
+            app.world_mut()
+                .spawn((TextInput, TextInputInactive(true)))
+                .id(),
+        ];
+        app.update();
 
-        let scale_factor = match window_ref {
-            Some(window_ref) => {
-                let window = match window_ref {
-                    WindowRef::Entity(w) => window_query.get(w).ok(),
-                    WindowRef::Primary => primary_window_query.get_single().ok(),
-                };
+        let active: Vec<Entity> = inputs
+            .into_iter()
+            .filter(|entity| !app.world().get::<TextInputInactive>(*entity).unwrap().0)
+            .collect();
+        assert_eq!(active, [inputs[1]]);
 
-                let Some(window) = window else {
-                    continue;
-                };
+        // With several autofocused text inputs, the last one wins.
+        let later = [
+            app.world_mut().spawn((TextInput, autofocus())).id(),
+            app.world_mut().spawn((TextInput, autofocus())).id(),
+        ];
+        app.update();
+        let active: Vec<Entity> = inputs
+            .into_iter()
+            .chain(later)
+            .filter(|entity| !app.world().get::<TextInputInactive>(*entity).unwrap().0)
+            .collect();
+        assert_eq!(active, [later[1]]);
+    }
 
-                window.scale_factor()
-            }
-            None => 1.0,
-        };
-        let cursor_pos = cursor_pos / scale_factor;
+    #[test]
+    fn grapheme_at_finds_clusters() {
+        let value = "a👍🏽e\u{301}";
+        assert_eq!(grapheme_at(value, 0), 0..1);
+        assert_eq!(grapheme_at(value, 1), 1..3);
+        assert_eq!(grapheme_at(value, 2), 1..3);
+        assert_eq!(grapheme_at(value, 3), 3..5);
+        assert_eq!(grapheme_at(value, 4), 3..5);
 
-        let box_pos = match style.left {
-            Val::Px(px) => -px,
-            _ => child_size - parent_size,
-        };
+        // Past the end, the range is empty.
+        assert_eq!(grapheme_at(value, 5), 5..5);
+        assert_eq!(grapheme_at("", 0), 0..0);
 
-        let relative_pos = cursor_pos - box_pos;
+        // Whether a regional indicator starts a flag depends on the indicators before it.
+        let flags = "🇫🇷🇩🇪";
+        assert_eq!(grapheme_at(flags, 1), 0..2);
+        assert_eq!(grapheme_at(flags, 2), 2..4);
+        assert_eq!(grapheme_at(flags, 3), 2..4);
 
-        if relative_pos < 0.0 || relative_pos > parent_size {
-            let req_px = parent_size * 0.5 - cursor_pos;
-            let req_px = req_px.clamp(parent_size - child_size, 0.0);
-            style.left = Val::Px(req_px);
-            parent_style.justify_content = JustifyContent::FlexStart;
-        }
+        let mut value = value.to_string();
+        let grapheme = grapheme_at(&value, 2);
+        remove_chars(&mut value, grapheme);
+        assert_eq!(value, "ae\u{301}");
     }
-}
 
-fn create(
-    trigger: Trigger<OnAdd, TextInputValue>,
-    mut commands: Commands,
-    query: Query<(
-        Entity,
-        &TextInputTextFont,
-        &TextInputTextColor,
-        &TextInputValue,
-        Option<&TextInputCursorPos>,
-        &TextInputInactive,
-        &TextInputSettings,
-        &TextInputPlaceholder,
-    )>,
-) {
-    if let Ok((
-        entity,
-        font,
-        color,
-        text_input,
-        maybe_cursor_pos,
-        inactive,
-        settings,
-        placeholder,
-    )) = &query.get(trigger.entity())
-    {
-        let cursor_pos = match maybe_cursor_pos {
-            None => {
-                let len = text_input.0.len();
-                commands.entity(*entity).insert(TextInputCursorPos(len));
-                len
-            }
-            Some(cursor_pos) => cursor_pos.0,
-        };
+    #[test]
+    fn delete_whole_graphemes() {
+        let mut app = app();
+        let entity = spawn(&mut app, (TextInput, TextInputValue("a👍🏽b👍🏽".to_string())));
+
+        press(&mut app, KeyCode::Backspace);
+        app.update();
+        assert_eq!(value(&app, entity), "a👍🏽b");
+        assert_eq!(cursor(&app, entity), 4);
 
-        let values = get_section_values(
-            &masked_value(&text_input.0, settings.mask_character),
-            cursor_pos,
+        set_cursor(&mut app, entity, 1);
+        press(&mut app, KeyCode::Delete);
+        app.update();
+        assert_eq!(value(&app, entity), "ab");
+        assert_eq!(cursor(&app, entity), 1);
+    }
+
+    #[test]
+    fn clear_on_focus_and_restore() {
+        let mut app = app();
+        let entity = spawn(
+            &mut app,
+            (
+                TextInput,
+                TextInputValue("0".to_string()),
+                TextInputInactive(true),
+                TextInputSettings {
+                    clear_on_focus: true,
+                    ..default()
+                },
+            ),
         );
+        assert_eq!(value(&app, entity), "0");
 
-        let text = commands
-            .spawn((
-                Text::default(),
-                TextLayout::new_with_linebreak(LineBreak::NoWrap),
-                Name::new("TextInputInner"),
-                TextInputInner,
-            ))
-            .with_children(|parent| {
-                // Pre-cursor
-                parent.spawn((TextSpan::new(values.0), font.0.clone()));
-
-                // Cursor
-                parent.spawn((
-                    TextSpan::new(values.1),
-                    TextFont {
-                        font: CURSOR_HANDLE,
-                        ..font.0.clone()
+        // Writing the same value is not a focus change.
+        set_inactive(&mut app, entity, true);
+        assert_eq!(value(&app, entity), "0");
+
+        set_inactive(&mut app, entity, false);
+        assert_eq!(value(&app, entity), "");
+
+        // An untouched value is restored on blur.
+        set_inactive(&mut app, entity, true);
+        assert_eq!(value(&app, entity), "0");
+
+        set_inactive(&mut app, entity, false);
+        type_text(&mut app, "5");
+        set_inactive(&mut app, entity, false);
+        assert_eq!(value(&app, entity), "5");
+        set_inactive(&mut app, entity, true);
+        assert_eq!(value(&app, entity), "5");
+    }
+
+    #[test]
+    fn numeric_invalid_submit_modes() {
+        use TextInputNumericInvalid::*;
+
+        for (on_invalid, submits, number, value_after) in [
+            (Clear, 1, None, "-"),
+            (KeepLast, 1, Some(4.0), "4"),
+            (Reject, 0, Some(4.0), "-"),
+        ] {
+            let mut app = app();
+            let entity = spawn(
+                &mut app,
+                (
+                    TextInput,
+                    TextInputNumeric {
+                        on_invalid,
+                        ..default()
                     },
-                    if inactive.0 {
-                        TextColor(Color::NONE)
-                    } else {
-                        color.0
+                    TextInputSettings {
+                        retain_on_submit: true,
+                        ..default()
                     },
-                ));
+                ),
+            );
+            type_text(&mut app, "4");
+            press(&mut app, KeyCode::Enter);
+            app.update();
+            events::<TextInputSubmitEvent>(&mut app);
 
-                // Post-cursor
-                parent.spawn((TextSpan::new(values.2), font.0.clone()));
-            })
-            .id();
+            press(&mut app, KeyCode::Backspace);
+            app.update();
+            type_text(&mut app, "-");
+            press(&mut app, KeyCode::Enter);
+            app.update();
 
-        let placeholder_font = placeholder
-            .text_font
-            .clone()
-            .unwrap_or_else(|| font.0.clone());
+            assert_eq!(
+                events::<TextInputSubmitEvent>(&mut app).len(),
+                submits,
+                "{on_invalid:?}"
+            );
+            assert_eq!(
+                app.world().get::<TextInputNumericValue>(entity).unwrap().0,
+                number,
+                "{on_invalid:?}"
+            );
+            assert_eq!(value(&app, entity), value_after, "{on_invalid:?}");
+        }
+    }
 
-        let placeholder_color = placeholder
-            .text_color
-            .unwrap_or_else(|| placeholder_color(&color.0));
+    #[test]
+    fn hidden_cursor_stays_transparent() {
+        let mut app = app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            300,
+        )));
+        let shown = spawn(&mut app, TextInput);
+        let hidden = spawn(&mut app, (TextInput, TextInputShowCursor(false)));
 
-        let placeholder_visible = inactive.0 && text_input.0.is_empty();
+        let cursor_color = |app: &App, entity: Entity| {
+            let cursor = app.world().get::<Children>(inner(app, entity)).unwrap()[0];
+            app.world().get::<TextColor>(cursor).unwrap().0
+        };
+        assert_ne!(cursor_color(&app, shown), Color::NONE);
 
-        let placeholder_text = commands
-            .spawn((
-                Text::new(&placeholder.value),
-                TextLayout::new_with_linebreak(LineBreak::NoWrap),
-                placeholder_font,
-                placeholder_color,
-                Name::new("TextInputPlaceholderInner"),
-                TextInputPlaceholderInner,
-                if placeholder_visible {
-                    Visibility::Inherited
-                } else {
-                    Visibility::Hidden
-                },
-                Node {
-                    position_type: PositionType::Absolute,
-                    ..default()
-                },
-            ))
-            .id();
+        // Through blinking, typing and focus changes.
+        for _ in 0..5 {
+            app.update();
+            assert_eq!(cursor_color(&app, hidden), Color::NONE);
+        }
+        type_text(&mut app, "a");
+        assert_eq!(cursor_color(&app, hidden), Color::NONE);
+        set_inactive(&mut app, hidden, true);
+        set_inactive(&mut app, hidden, false);
+        assert_eq!(cursor_color(&app, hidden), Color::NONE);
+    }
 
-        let overflow_container = commands
-            .spawn((
-                Node {
-                    overflow: Overflow::clip(),
-                    justify_content: JustifyContent::FlexEnd,
-                    max_width: Val::Percent(100.),
+    #[test]
+    fn multi_char_character_event() {
+        let mut app = app();
+        let entity = spawn(&mut app, TextInput);
+
+        send_text(&mut app, "ab");
+        app.update();
+        assert_eq!(value(&app, entity), "ab");
+        assert_eq!(cursor(&app, entity), 2);
+
+        set_cursor(&mut app, entity, 1);
+        send_text(&mut app, "😀👍");
+        app.update();
+        assert_eq!(value(&app, entity), "a😀👍b");
+        assert_eq!(cursor(&app, entity), 3);
+    }
+
+    #[test]
+    fn two_char_character_event() {
+        let mut app = app();
+        let entity = spawn(
+            &mut app,
+            (
+                TextInput,
+                TextInputValue("x".to_string()),
+                TextInputSettings {
+                    max_length: Some(4),
                     ..default()
                 },
-                Name::new("TextInputOverflowContainer"),
-            ))
-            .id();
+            ),
+        );
+        set_cursor(&mut app, entity, 0);
 
-        commands.entity(overflow_container).add_child(text);
-        commands
-            .entity(trigger.entity())
-            .add_children(&[overflow_container, placeholder_text]);
+        send_text(&mut app, "e\u{301}");
+        app.update();
+        assert_eq!(value(&app, entity), "e\u{301}x");
+        assert_eq!(cursor(&app, entity), 2);
 
-        // Prevent clicks from registering on UI elements underneath the text input.
-        commands.entity(trigger.entity()).insert(FocusPolicy::Block);
+        // Only the chars that fit are inserted, and the cursor advances by as many.
+        send_text(&mut app, "yz");
+        app.update();
+        assert_eq!(value(&app, entity), "e\u{301}yx");
+        assert_eq!(cursor(&app, entity), 3);
     }
-}
 
-// Shows or hides the cursor based on the text input's [`TextInputInactive`] property.
-fn show_hide_cursor(
-    mut input_query: Query<
-        (
-            Entity,
-            &TextInputTextColor,
-            &mut TextInputCursorTimer,
-            &TextInputInactive,
-        ),
-        Changed<TextInputInactive>,
-    >,
-    inner_text: InnerText,
-    mut writer: TextUiWriter,
-) {
-    for (entity, color, mut cursor_timer, inactive) in &mut input_query {
-        let Some(inner) = inner_text.inner_entity(entity) else {
-            continue;
-        };
+    #[test]
+    fn release_binding() {
+        let mut app = app();
+        app.insert_resource(TextInputNavigationBindings(vec![(
+            TextInputAction::LineStart,
+            TextInputBinding::new(KeyCode::Tab, []).on_release(),
+        )]));
+        let entity = spawn(&mut app, (TextInput, TextInputValue("abc".to_string())));
 
-        *writer.color(inner, 1) = if inactive.0 {
-            TextColor(Color::NONE)
-        } else {
-            color.0
-        };
+        // Pressing the key does nothing, and it isn't inserted.
+        send_key(&mut app, KeyCode::Tab, Key::Tab, ButtonState::Pressed);
+        app.update();
+        assert_eq!(cursor(&app, entity), 3);
+        assert_eq!(value(&app, entity), "abc");
 
-        cursor_timer.timer.reset();
+        send_key(&mut app, KeyCode::Tab, Key::Tab, ButtonState::Released);
+        app.update();
+        assert_eq!(cursor(&app, entity), 0);
     }
-}
 
-// Blinks the cursor on a timer.
-fn blink_cursor(
-    mut input_query: Query<(
-        Entity,
-        &TextInputTextColor,
-        &mut TextInputCursorTimer,
-        Ref<TextInputInactive>,
-    )>,
-    inner_text: InnerText,
-    mut writer: TextUiWriter,
-    time: Res<Time>,
-) {
-    for (entity, color, mut cursor_timer, inactive) in &mut input_query {
-        if inactive.0 {
-            continue;
-        }
+    #[test]
+    fn debounced_change_event() {
+        let mut app = app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        let entity = spawn(
+            &mut app,
+            (
+                TextInput,
+                TextInputChangeDebounce(Duration::from_millis(300)),
+            ),
+        );
+        events::<TextInputChangedEvent>(&mut app);
 
-        if cursor_timer.is_changed() && cursor_timer.should_reset {
-            cursor_timer.timer.reset();
-            cursor_timer.should_reset = false;
+        // Each edit restarts the delay.
+        type_text(&mut app, "a");
+        app.update();
+        type_text(&mut app, "b");
+        app.update();
+        app.update();
+        assert!(events::<TextInputChangedEvent>(&mut app).is_empty());
 
-            if let Some(inner) = inner_text.inner_entity(entity) {
-                *writer.color(inner, 1) = color.0;
-            };
+        app.update();
+        app.update();
+        let changed = events::<TextInputChangedEvent>(&mut app);
+        assert_eq!(changed.len(), 1);
+        assert_eq!(changed[0].entity, entity);
+        assert_eq!(changed[0].value, "ab");
 
-            continue;
+        for _ in 0..5 {
+            app.update();
         }
+        assert!(events::<TextInputChangedEvent>(&mut app).is_empty());
+    }
 
-        if !cursor_timer.timer.tick(time.delta()).just_finished() {
-            continue;
-        }
+    #[test]
+    fn custom_inner_ready_event() {
+        let mut app = app();
+        let root = app.world_mut().spawn(Node::default()).id();
+        let entity = app.world_mut().spawn(Node::default()).set_parent(root).id();
+        let custom = app
+            .world_mut()
+            .spawn((Text::default(), TextInputInner))
+            .set_parent(entity)
+            .with_children(|parent| {
+                parent.spawn(TextSpan::default());
+                parent.spawn(TextSpan::default());
+                parent.spawn(TextSpan::default());
+            })
+            .id();
+        app.world_mut().entity_mut(entity).insert(TextInput);
+        app.update();
+        events::<TextInputReadyEvent>(&mut app);
 
-        let Some(inner) = inner_text.inner_entity(entity) else {
-            continue;
-        };
+        set_layout(&mut app, custom, 50.0);
+        app.update();
+        let ready = events::<TextInputReadyEvent>(&mut app);
+        assert_eq!(ready.len(), 1);
+        assert_eq!(ready[0].entity, entity);
 
-        if writer.color(inner, 1).0 != Color::NONE {
-            *writer.color(inner, 1) = TextColor(Color::NONE);
-        } else {
-            *writer.color(inner, 1) = color.0;
-        }
+        // The custom inner entity isn't scrolled inside the text input.
+        set_size(&mut app, entity, 20.0);
+        set_layout(&mut app, custom, 50.0);
+        app.update();
+        assert_eq!(app.world().get::<Node>(custom).unwrap().left, Val::Auto);
+        assert_eq!(
+            app.world().get::<Node>(entity).unwrap().justify_content,
+            JustifyContent::Default
+        );
     }
-}
 
-fn show_hide_placeholder(
-    input_query: Query<
-        (&Children, &TextInputValue, &TextInputInactive),
-        Or<(Changed<TextInputValue>, Changed<TextInputInactive>)>,
-    >,
-    mut vis_query: Query<&mut Visibility, With<TextInputPlaceholderInner>>,
-) {
-    for (children, text, inactive) in &input_query {
-        let mut iter = vis_query.iter_many_mut(children);
-        while let Some(mut inner_vis) = iter.fetch_next() {
-            inner_vis.set_if_neq(if text.0.is_empty() && inactive.0 {
-                Visibility::Inherited
-            } else {
-                Visibility::Hidden
-            });
+    #[test]
+    fn despawning_focused_input_clears_focus() {
+        let mut app = app();
+        let other = spawn(&mut app, (TextInput, TextInputInactive(true)));
+        let entity = spawn(&mut app, TextInput);
+        assert_eq!(app.world().resource::<FocusedTextInput>().0, Some(entity));
+
+        app.world_mut().entity_mut(entity).despawn_recursive();
+        type_text(&mut app, "a");
+        assert_eq!(app.world().resource::<FocusedTextInput>().0, None);
+        assert_eq!(value(&app, other), "");
+    }
+
+    #[cfg(feature = "recording")]
+    #[test]
+    fn record_and_replay_round_trip() {
+        let mut app = app();
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        let recorded = spawn(&mut app, (TextInput, TextInputRecording::default()));
+
+        type_text(&mut app, "ab");
+        app.update();
+        press(&mut app, KeyCode::ArrowLeft);
+        app.update();
+        type_text(&mut app, "c d");
+        press(&mut app, KeyCode::Backspace);
+        app.update();
+        assert_eq!(value(&app, recorded), "ac b");
+        assert_eq!(cursor(&app, recorded), 3);
+
+        let replay = app
+            .world()
+            .get::<TextInputRecording>(recorded)
+            .unwrap()
+            .replay();
+        let replayed = spawn(&mut app, (TextInput, TextInputInactive(true), replay));
+        for _ in 0..10 {
+            app.update();
         }
+
+        assert!(app
+            .world()
+            .get::<TextInputReplay>(replayed)
+            .unwrap()
+            .is_finished());
+        assert_eq!(value(&app, replayed), "ac b");
+        assert_eq!(cursor(&app, replayed), 3);
     }
-}
 
-fn update_style(
-    mut input_query: Query<(Entity, &TextInputTextFont), Changed<TextInputTextFont>>,
-    inner_text: InnerText,
-    mut writer: TextUiWriter,
-) {
-    for (entity, font) in &mut input_query {
-        let Some(inner) = inner_text.inner_entity(entity) else {
-            continue;
-        };
+    #[test]
+    fn cursor_advances_past_trailing_spaces() {
+        let mut app = layout_app();
+        let entity = spawn(&mut app, TextInput);
 
-        *writer.font(inner, 0) = font.0.clone();
-        *writer.font(inner, 1) = TextFont {
-            font: CURSOR_HANDLE,
-            ..font.0.clone()
+        // The cursor's glyph and the width of the inner text, once the value has been laid out.
+        let measure = |app: &mut App| {
+            app.update();
+            app.update();
+            let inner = inner(app, entity);
+            let layout = app.world().get::<TextLayoutInfo>(inner).unwrap();
+            let cursor = layout.glyphs.iter().find(|glyph| glyph.span_index == 1);
+            let width = app.world().get::<ComputedNode>(inner).unwrap().size().x;
+            (cursor.unwrap().position.x, width)
         };
-        *writer.font(inner, 2) = font.0.clone();
+
+        // A value of only spaces.
+        let mut last = measure(&mut app);
+        for _ in 0..3 {
+            type_text(&mut app, " ");
+            let next = measure(&mut app);
+            assert!(next.0 > last.0 && next.1 > last.1, "{next:?} <= {last:?}");
+            last = next;
+        }
+
+        // Spaces after the text.
+        type_text(&mut app, "a");
+        last = measure(&mut app);
+        for _ in 0..3 {
+            type_text(&mut app, " ");
+            let next = measure(&mut app);
+            assert!(next.0 > last.0 && next.1 > last.1, "{next:?} <= {last:?}");
+            last = next;
+        }
+        assert_eq!(cursor(&app, entity), 7);
     }
-}
 
-fn update_color(
-    mut input_query: Query<
-        (Entity, &TextInputTextColor, &TextInputInactive),
-        Changed<TextInputTextColor>,
-    >,
-    inner_text: InnerText,
-    mut writer: TextUiWriter,
-) {
-    for (entity, color, inactive) in &mut input_query {
-        let Some(inner) = inner_text.inner_entity(entity) else {
-            continue;
-        };
-        *writer.color(inner, 0) = color.0;
-        *writer.color(inner, 1) = if inactive.0 {
-            TextColor(Color::NONE)
-        } else {
-            color.0
-        };
-        *writer.color(inner, 2) = color.0;
+    // Pastes `text` with a binding for `TextInputAction::InsertText`, like an app reading the clipboard would.
+    fn paste(app: &mut App, text: &str) {
+        app.world_mut()
+            .resource_mut::<TextInputNavigationBindings>()
+            .0
+            .insert(
+                0,
+                (
+                    TextInputAction::InsertText(text.to_string()),
+                    TextInputBinding::new(KeyCode::F1, []),
+                ),
+            );
+        press(app, KeyCode::F1);
+        app.update();
     }
-}
 
-fn get_section_values(value: &str, cursor_pos: usize) -> (String, String, String) {
-    let before = value.chars().take(cursor_pos).collect();
-    let after = value.chars().skip(cursor_pos).collect();
+    #[test]
+    fn paste_multi_grapheme_text() {
+        let mut app = app();
+        let entity = spawn(&mut app, (TextInput, TextInputValue("[]".to_string())));
+        set_cursor(&mut app, entity, 1);
 
-    // If the cursor is between two characters, use the zero-width cursor.
-    let cursor = if cursor_pos >= value.chars().count() {
-        "}".to_string()
-    } else {
-        "|".to_string()
-    };
+        paste(&mut app, "e\u{301}👍🏽x");
+        assert_eq!(value(&app, entity), "[e\u{301}👍🏽x]");
+        assert_eq!(cursor(&app, entity), 6);
 
-    (before, cursor, after)
-}
+        // The cursor is after whole graphemes, so moving left steps over them.
+        press(&mut app, KeyCode::ArrowLeft);
+        press(&mut app, KeyCode::ArrowLeft);
+        app.update();
+        assert_eq!(cursor(&app, entity), 3);
+    }
 
-fn remove_char_at(input: &str, index: usize) -> String {
-    input
-        .chars()
-        .enumerate()
-        .filter_map(|(i, c)| if i != index { Some(c) } else { None })
-        .collect()
-}
+    #[test]
+    fn paste_over_max_length() {
+        let mut app = app();
+        let entity = spawn(
+            &mut app,
+            (
+                TextInput,
+                TextInputValue("abc".to_string()),
+                TextInputSettings {
+                    max_length: Some(5),
+                    ..default()
+                },
+            ),
+        );
 
-fn byte_pos(input: &str, char_pos: usize) -> usize {
-    let mut char_indices = input.char_indices();
-    char_indices
-        .nth(char_pos)
-        .map(|(pos, _)| pos)
-        .unwrap_or(input.len())
-}
+        paste(&mut app, "defgh");
+        assert_eq!(value(&app, entity), "abcde");
+        assert_eq!(cursor(&app, entity), 5);
 
-fn masked_value(value: &str, mask: Option<char>) -> String {
-    mask.map_or_else(
-        || value.to_string(),
-        |c| value.chars().map(|_| c).collect::<String>(),
-    )
-}
+        // A full text input doesn't change.
+        paste(&mut app, "x");
+        assert_eq!(value(&app, entity), "abcde");
+        assert_eq!(cursor(&app, entity), 5);
+    }
 
-fn placeholder_color(color: &TextColor) -> TextColor {
-    TextColor(color.with_alpha(color.alpha() * 0.25))
+    #[test]
+    fn paste_at_mid_value_cursor() {
+        let mut app = app();
+        let entity = spawn(&mut app, (TextInput, TextInputValue("abef".to_string())));
+        set_cursor(&mut app, entity, 2);
+
+        paste(&mut app, "cd");
+        assert_eq!(value(&app, entity), "abcdef");
+        assert_eq!(cursor(&app, entity), 4);
+    }
+
+    #[test]
+    fn paste_into_filtered_limited_masked_input() {
+        let mut app = app();
+        let entity = spawn(
+            &mut app,
+            (
+                TextInput,
+                TextInputNumeric::default(),
+                TextInputValue("19".to_string()),
+                TextInputSettings {
+                    max_length: Some(6),
+                    mask_character: Some('*'),
+                    ..default()
+                },
+            ),
+        );
+        set_cursor(&mut app, entity, 1);
+
+        // Rejected chars are removed before truncating, so they don't use up the remaining room.
+        paste(&mut app, "2a3b\t4");
+        assert_eq!(value(&app, entity), "12349");
+        assert_eq!(cursor(&app, entity), 4);
+
+        paste(&mut app, "x5");
+        assert_eq!(value(&app, entity), "123459");
+        assert_eq!(cursor(&app, entity), 5);
+        assert_eq!(displayed(&app, entity), "*****|*");
+    }
 }